@@ -0,0 +1,149 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use crate::data::ability_calc::AbilityNumbers;
+use crate::data::build_stats::BuildStats;
+use crate::data::formulas::ScalingStat;
+use crate::data::gods::GodStats;
+use crate::utils::format::{format_stat_name, format_stat_value};
+use crate::utils::unslug;
+
+#[component]
+pub fn BuildStatsTable(stats: BuildStats) -> Element {
+    if stats.is_empty() {
+        return rsx! { div {} };
+    }
+
+    rsx! {
+        div {
+            class: "build-stats",
+            for total in stats.totals() {
+                div {
+                    key: "{total.stat:?}",
+                    class: if total.is_capped() { "build-stats-row capped" } else { "build-stats-row" },
+                    title: if total.is_capped() {
+                        format!("Capped - build has {}", format_stat_value(&total.stat, total.raw))
+                    } else {
+                        String::new()
+                    },
+                    span {
+                        class: "stat-value",
+                        "{format_stat_value(&total.stat, total.value)}"
+                    }
+                    span {
+                        class: "stat-name",
+                        "{format_stat_name(&total.stat)}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn GodStatsTable(stats: GodStats) -> Element {
+    let rows = [
+        ("Health", format!("{:.0}", stats.health)),
+        ("Mana", format!("{:.0}", stats.mana)),
+        ("Physical Protection", format!("{:.0}", stats.physical_protection)),
+        ("Magical Protection", format!("{:.0}", stats.magical_protection)),
+        ("HP5", format!("{:.1}", stats.hp5)),
+        ("MP5", format!("{:.1}", stats.mp5)),
+        ("Basic Attack Damage", format!("{:.0}", stats.basic_attack_damage)),
+        ("Attack Speed", format!("{:.2}/s", stats.attack_speed)),
+        ("Movement Speed", format!("{:.0}", stats.movement_speed)),
+    ];
+
+    rsx! {
+        div {
+            class: "build-stats",
+            for (name, value) in rows {
+                div {
+                    key: "{name}",
+                    class: "build-stats-row",
+                    span {
+                        class: "stat-value",
+                        "{value}"
+                    }
+                    span {
+                        class: "stat-name",
+                        "{name}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Hover breakdown of an ability's damage, cooldown and cost at its current rank
+#[component]
+pub fn AbilityNumbersPanel(numbers: AbilityNumbers) -> Element {
+    if numbers.damage.is_empty() && numbers.cooldown.is_none() && numbers.cost.is_none() {
+        return rsx! { div {} };
+    }
+
+    let headline = numbers.primary_damage().map(|line| format!("{:.0}", line.total()));
+
+    rsx! {
+        div {
+            class: "skill-calc",
+            span {
+                class: "skill-calc-headline",
+                match headline {
+                    Some(damage) => rsx! { "{damage}" },
+                    None => rsx! { "-" },
+                }
+            }
+            div {
+                class: "skill-calc-breakdown",
+                div {
+                    class: "skill-calc-rank",
+                    if numbers.rank == 0 { "Not leveled yet (rank 1 values)" } else { "Rank {numbers.rank}" }
+                }
+                for line in numbers.damage.iter() {
+                    div {
+                        key: "{line.key}",
+                        class: "build-stats-row",
+                        span {
+                            class: "stat-value",
+                            "{line.total():.0}"
+                        }
+                        span {
+                            class: "stat-name",
+                            "{unslug(&line.key)}: {line.base:.0} base"
+                            for (stat, ratio) in line.scalings.iter() {
+                                " + {format_scaling(*stat, *ratio)}"
+                            }
+                            if line.bonus > 0.0 {
+                                " = +{line.bonus:.0}"
+                            }
+                        }
+                    }
+                }
+                if let (Some(base), Some(cooldown)) = (numbers.base_cooldown, numbers.cooldown) {
+                    div {
+                        class: "build-stats-row",
+                        span { class: "stat-value", "{cooldown:.1}s" }
+                        span {
+                            class: "stat-name",
+                            if cooldown < base { "Cooldown (base {base:.1}s)" } else { "Cooldown" }
+                        }
+                    }
+                }
+                if let Some(cost) = numbers.cost {
+                    div {
+                        class: "build-stats-row",
+                        span { class: "stat-value", "{cost:.0}" }
+                        span { class: "stat-name", "Cost" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn format_scaling(stat: ScalingStat, ratio: f64) -> String {
+    match stat {
+        ScalingStat::Level => format!("{} per Level", ratio),
+        _ => format!("{}% {}", (ratio * 100.0).round(), stat.label()),
+    }
+}
@@ -0,0 +1,220 @@
+#![allow(non_snake_case)]
+use std::rc::Rc;
+use dioxus::prelude::*;
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::window;
+use crate::components::ItemCard;
+use crate::data::search::{guides_mentioning, search, SearchDoc, SearchTarget};
+use crate::routes::links::{LinkScope, LinkState};
+use crate::utils::format_god_image_name;
+use crate::{FilteredClass, FilteredRole, SelectedBuild, Route};
+
+const MAX_RESULTS: usize = 12;
+
+/// Ctrl+K (Cmd+K) search over gods, items, abilities and guides
+#[component]
+pub fn CommandPalette() -> Element {
+    let mut open = use_signal(|| false);
+    let mut query = use_signal(String::new);
+    let mut active = use_signal(|| 0usize);
+    // Item whose card is shown next to the results
+    let mut pinned_item = use_signal(|| None::<String>);
+
+    let class = use_context::<Signal<FilteredClass>>();
+    let role = use_context::<Signal<FilteredRole>>();
+    let mut selected_build = use_context::<Signal<SelectedBuild>>();
+
+    let keydown = use_hook(|| {
+        let closure = Closure::<dyn FnMut(_)>::new(move |event: web_sys::KeyboardEvent| {
+            if (event.ctrl_key() || event.meta_key()) && !event.alt_key() && event.key().to_lowercase() == "k" {
+                event.prevent_default();
+                let opening = !open();
+                if opening {
+                    query.set(String::new());
+                    active.set(0);
+                    pinned_item.set(None);
+                }
+                open.set(opening);
+            }
+        });
+        if let Some(document) = window().and_then(|w| w.document()) {
+            let _ = document.add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
+        }
+        Rc::new(closure)
+    });
+    use_drop(move || {
+        if let Some(document) = window().and_then(|w| w.document()) {
+            let _ = document.remove_event_listener_with_callback("keydown", keydown.as_ref().as_ref().unchecked_ref());
+        }
+    });
+
+    let trigger = rsx! {
+        button {
+            class: "palette-trigger",
+            title: "Search (Ctrl+K)",
+            onclick: move |_| {
+                query.set(String::new());
+                active.set(0);
+                pinned_item.set(None);
+                open.set(true);
+            },
+            "Search"
+            kbd { "Ctrl K" }
+        }
+    };
+    if !open() {
+        return trigger;
+    }
+
+    let results = search(&query(), MAX_RESULTS);
+    let active_index = active().min(results.len().saturating_sub(1));
+
+    let mut choose = move |target: SearchTarget| {
+        let link = |god: &str, build_role: Option<String>| LinkState {
+            god: Some(god.to_string()),
+            build_role,
+            class: class().0,
+            role: role().0,
+        }.route(LinkScope::Home);
+
+        match target {
+            SearchTarget::Item(item_id) => {
+                pinned_item.set(Some(item_id));
+                return;
+            }
+            SearchTarget::God(god) | SearchTarget::Ability { god, .. } => {
+                navigator().push(link(&god, None));
+            }
+            SearchTarget::Guide { god, role, id } => {
+                selected_build.set(SelectedBuild(Some(id)));
+                navigator().push(link(&god, Some(role)));
+            }
+        }
+        open.set(false);
+    };
+
+    let keyboard_results: Vec<SearchTarget> = results.iter().map(|doc| doc.target.clone()).collect();
+
+    rsx! {
+        {trigger}
+        div {
+            class: "palette-backdrop",
+            onclick: move |_| open.set(false),
+            div {
+                class: "palette",
+                onclick: move |evt| evt.stop_propagation(),
+                input {
+                    class: "palette-input",
+                    r#type: "text",
+                    placeholder: "Search gods, items, abilities and guides…",
+                    value: "{query}",
+                    autofocus: true,
+                    oninput: move |evt| {
+                        query.set(evt.value());
+                        active.set(0);
+                    },
+                    onkeydown: move |evt: KeyboardEvent| match evt.key() {
+                        Key::ArrowDown => {
+                            evt.prevent_default();
+                            if active_index + 1 < keyboard_results.len() {
+                                active.set(active_index + 1);
+                            }
+                        }
+                        Key::ArrowUp => {
+                            evt.prevent_default();
+                            active.set(active_index.saturating_sub(1));
+                        }
+                        Key::Enter => {
+                            if let Some(target) = keyboard_results.get(active_index) {
+                                choose(target.clone());
+                            }
+                        }
+                        Key::Escape => {
+                            if pinned_item().is_some() {
+                                pinned_item.set(None);
+                            } else {
+                                open.set(false);
+                            }
+                        }
+                        _ => {}
+                    },
+                }
+                div {
+                    class: "palette-body",
+                    ul {
+                        class: "palette-results",
+                        if results.is_empty() && !query().trim().is_empty() {
+                            li { class: "palette-empty", "No matches" }
+                        }
+                        for (i, doc) in results.iter().enumerate() {
+                            li {
+                                key: "{i}-{doc.title}",
+                                class: if i == active_index { "palette-result active" } else { "palette-result" },
+                                onmouseenter: move |_| active.set(i),
+                                onclick: {
+                                    let target = doc.target.clone();
+                                    move |_| choose(target.clone())
+                                },
+                                {result_icon(doc)}
+                                span { class: "palette-title", "{doc.title}" }
+                                span { class: "palette-detail", "{doc.detail}" }
+                                span { class: "palette-kind", "{doc.target.kind()}" }
+                            }
+                        }
+                    }
+                    if let Some(item_id) = pinned_item() {
+                        div {
+                            class: "palette-preview",
+                            ItemCard { item_id: item_id.clone() }
+                            Link {
+                                class: "palette-link",
+                                to: Route::Items { item: item_id.clone() },
+                                onclick: move |_| open.set(false),
+                                "Open in item browser"
+                            }
+                            h5 { "Guides using this item" }
+                            {
+                                let guides = guides_mentioning(&item_id);
+                                rsx! {
+                                    if guides.is_empty() {
+                                        p { class: "palette-empty", "No curated guide builds it" }
+                                    }
+                                    for guide in guides {
+                                        button {
+                                            key: "{guide.title}",
+                                            class: "palette-guide",
+                                            onclick: {
+                                                let target = guide.target.clone();
+                                                move |_| choose(target.clone())
+                                            },
+                                            "{guide.title}"
+                                            span { class: "palette-detail", "{guide.detail}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                div {
+                    class: "palette-hint",
+                    "↑↓ to move · Enter to open · Esc to close"
+                }
+            }
+        }
+    }
+}
+
+fn result_icon(doc: &SearchDoc) -> Element {
+    match &doc.target {
+        SearchTarget::God(god) | SearchTarget::Guide { god, .. } => rsx! {
+            img { class: "palette-icon", src: "/assets/gods/{format_god_image_name(god)}.png" }
+        },
+        SearchTarget::Item(item) => rsx! {
+            img { class: "palette-icon", src: "/assets/items/{item}.png" }
+        },
+        SearchTarget::Ability { .. } => rsx! {
+            span { class: "palette-icon" }
+        },
+    }
+}
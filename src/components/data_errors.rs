@@ -0,0 +1,40 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use crate::data::registry::{DataError, DATA};
+
+fn error_list(errors: &[DataError]) -> Element {
+    rsx! {
+        ul {
+            class: "data-errors-list",
+            for (i, error) in errors.iter().enumerate() {
+                li {
+                    key: "{i}",
+                    code { "{error.file}" }
+                    " at "
+                    code { "{error.path}" }
+                    p { "{error.reason}" }
+                }
+            }
+        }
+    }
+}
+
+/// Shown instead of the app when bundled data fails to load
+#[component]
+pub fn DataErrorScreen() -> Element {
+    rsx! {
+        div {
+            class: "data-errors",
+            img { src: "/assets/logo.svg" }
+            h2 { "Grappul couldn't load its game data" }
+            p { "The files below are broken, so there's nothing to show yet. Fix them and rebuild." }
+            {error_list(&DATA.load_errors)}
+
+            if !DATA.reference_errors.is_empty() {
+                h3 { "Broken references" }
+                p { "These IDs point at nothing. Some may go away once the files above load." }
+                {error_list(&DATA.reference_errors)}
+            }
+        }
+    }
+}
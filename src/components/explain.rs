@@ -1,11 +1,20 @@
 #![allow(non_snake_case)]
 
 use dioxus::prelude::*;
-use crate::components::{Item, Ability, NoBuildCTA, MarkdownRenderer};
-use crate::data::gods::GODS;
-use crate::data::guides::GUIDES;
+use crate::components::{Item, Ability, GuideImpactList, NoBuildCTA, MarkdownRenderer, BuildStatsTable, GodStatsTable, AbilityNumbersPanel, ShareButton};
+use crate::data::gods::{GODS, MAX_LEVEL};
+use crate::data::guides::Guide;
+use crate::data::aliases::same_role;
+use crate::data::saved_guides::SavedGuides;
+use crate::data::share::encode_guide;
+use crate::data::guide_sources::{distinct_roles, guides_for_god, GuideSource};
+use crate::data::build_stats::BuildStats;
+use crate::data::ability_calc::{god_ability_numbers, AbilityNumbers, Attacker};
 use crate::components::timelinepiece::TimelinePiece;
-use crate::{SelectedGod, FilteredRole, SelectedRole};
+use crate::data::patches::current_patch;
+use crate::data::staleness::impact_since_written;
+use crate::data::registry::DATA;
+use crate::{SelectedGod, FilteredRole, SelectedRole, SelectedBuild, Route};
 use std::collections::HashMap;
 use lazy_static::lazy_static;
 use web_sys::window;
@@ -14,13 +23,10 @@ use wasm_bindgen::{JsCast, closure::Closure};
 pub const INLINE_ICON_SIZE: u32 = 32;
 
 lazy_static! {
-    static ref SKILL_ORDER: HashMap<String, Vec<u8>> = {
-        let json_str = include_str!("../data/json/skill_order.json");
-        serde_json::from_str(json_str).unwrap()
-    };
+    pub static ref SKILL_ORDER: &'static HashMap<String, Vec<u8>> = &DATA.skill_order;
 }
 
-fn render_item_row(items: &[String], size: Option<u32>) -> Element {
+pub fn render_item_row(items: &[String], size: Option<u32>) -> Element {
     rsx! {
         div {
             class: "itemrow",
@@ -37,7 +43,7 @@ fn render_item_row(items: &[String], size: Option<u32>) -> Element {
 }
 
 
-fn skill_point(skill_order: &[u8], skill_idx: usize, i: usize) -> Element {
+fn skill_point(skill_order: &[u8], skill_idx: usize, i: usize, diverged: bool) -> Element {
     let mut level = None;
     for (level_idx, &skill) in skill_order.iter().enumerate() {
         if skill as usize == (skill_idx + 1) && level_idx == i {
@@ -50,6 +56,7 @@ fn skill_point(skill_order: &[u8], skill_idx: usize, i: usize) -> Element {
         Some(_) => format!("point level skill{}", skill_idx),
         None => "point".to_string()
     };
+    let class = if diverged { format!("{} diverged", class) } else { class };
 
     rsx! {  
         div {
@@ -62,13 +69,17 @@ fn skill_point(skill_order: &[u8], skill_idx: usize, i: usize) -> Element {
     }
 }
 
-fn skill_row(ability: &str, skill_order: &[u8], skill_idx: usize) -> Element {
+/// One ability's row of the skill grid. Levels in `diverging` are highlighted.
+pub fn skill_row(ability: &str, skill_order: &[u8], skill_idx: usize, numbers: Option<AbilityNumbers>, diverging: &[usize]) -> Element {
     rsx! {
         div {
             class: "skillrow",
             Ability { ab: ability.to_string(), size: 32 }
             for i in 0..20 {
-                {skill_point(&skill_order, skill_idx, i)}
+                {skill_point(skill_order, skill_idx, i, diverging.contains(&i))}
+            }
+            if let Some(numbers) = numbers {
+                AbilityNumbersPanel { numbers }
             }
         }
     }
@@ -80,6 +91,8 @@ pub fn Explain() -> Element {
     let god = use_context::<Signal<SelectedGod>>();
     let filtered_role = use_context::<Signal<FilteredRole>>();
     let mut selected_role = use_context::<Signal<SelectedRole>>();
+    let mut selected_build = use_context::<Signal<SelectedBuild>>();
+    let saved_guides = use_context::<Signal<SavedGuides>>();
     let mut selected_build_role = use_signal(String::new);
 
     // Early return if no god is selected
@@ -87,20 +100,17 @@ pub fn Explain() -> Element {
         return rsx! { div {} }
     };
 
-    let god_info = match GODS.get(&god_name) {
-        Some(info) => info,
-        None => return rsx! { div { "No god information found" } }
-    };
-
-    let builds = GUIDES.get(&god_name);
+    if !GODS.contains_key(&god_name) {
+        return rsx! { div { "No god information found" } }
+    }
 
-    // Get the skill order for this god (will be updated later based on selected build)
-    let default_skill_order = SKILL_ORDER.get(&god_name).unwrap_or(&vec![]).clone();
+    // Curated guides plus the ones saved from the creator
+    let builds = guides_for_god(&god_name, &saved_guides.read().0);
 
     let display_name = god_name.replace("_", " ");
 
     // Check if there are any builds
-    let Some(builds) = builds else {
+    if builds.is_empty() {
         return rsx! {
             div {
                 class: "explain-container",
@@ -121,16 +131,12 @@ pub fn Explain() -> Element {
                 }
             }
         }
-    };
+    }
 
     // Get unique roles for this god
     let available_roles: Vec<String> = {
-        let mut roles = std::collections::HashSet::new();
-        for build in builds.iter() {
-            roles.insert(build.role.clone());
-        }
-        let mut roles: Vec<String> = roles.into_iter().collect();
-        roles.sort(); // Sort for consistent ordering
+        let mut roles = distinct_roles(&builds);
+        roles.sort_by_key(|role| role.to_lowercase()); // Sort for consistent ordering
         roles
     };
 
@@ -153,10 +159,18 @@ pub fn Explain() -> Element {
         selected_build_role.set(current_role.clone());
     }
 
-    // Get the build for the selected role
-    let build = builds.iter()
-        .find(|build| build.role == current_role)
+    // Guides for the selected role, curated first
+    let role_entries: Vec<_> = builds.iter()
+        .filter(|entry| same_role(&entry.guide.role, &current_role))
+        .collect();
+
+    // Prefer the guide picked in the source switcher, then the first one for the role
+    let entry = role_entries.iter()
+        .find(|entry| selected_build.read().0.as_ref() == Some(&entry.id))
+        .or_else(|| role_entries.first())
+        .copied()
         .unwrap_or(&builds[0]);
+    let build = &entry.guide;
     
     // Add keyboard navigation for roles
     use_effect({
@@ -195,7 +209,7 @@ pub fn Explain() -> Element {
                             "ArrowLeft" | "ArrowRight" => {
                                 // Find current role index based on the actual displayed build
                                 let current_index = available_roles.iter()
-                                    .position(|r| r == &build_role)
+                                    .position(|r| same_role(r, &build_role))
                                     .unwrap_or(0);
                                 
                                 let new_index = match key.as_str() {
@@ -256,7 +270,32 @@ pub fn Explain() -> Element {
             }
             div {
                 class: "explain-title",
-                h1 { "{display_name}" }
+                h1 {
+                    "{display_name}"
+                    span {
+                        class: "guide-badge {entry.source.class()}",
+                        "{entry.source.label()}"
+                    }
+                }
+                if role_entries.len() > 1 {
+                    div {
+                        class: "guide-sources",
+                        for option in role_entries.iter() {
+                            button {
+                                key: "{option.id}",
+                                class: if option.id == entry.id { "guide-source selected" } else { "guide-source" },
+                                onclick: {
+                                    let id = option.id.clone();
+                                    move |_| selected_build.set(SelectedBuild(Some(id.clone())))
+                                },
+                                span { class: "guide-badge {option.source.class()}", "{option.source.label()}" }
+                                if option.source == GuideSource::Mine {
+                                    "{option.label()}"
+                                }
+                            }
+                        }
+                    }
+                }
             }
             div {
                 class: "role-buttons",
@@ -264,7 +303,7 @@ pub fn Explain() -> Element {
                     button {
                         key: "{role_name}",
                         class: {
-                            if role_name == build.role { "role-button selected" } else { "role-button" }
+                            if same_role(&role_name, &build.role) { "role-button selected" } else { "role-button" }
                         },
                         onclick: {
                             let role = role_name.clone();
@@ -277,10 +316,82 @@ pub fn Explain() -> Element {
                         "{role_name}"
                     }
                 }
+                button {
+                    class: "role-button",
+                    title: "Time-to-kill against a target",
+                    onclick: move |_| {
+                        navigator().push(Route::Simulate);
+                    },
+                    "Simulate"
+                }
+                button {
+                    class: "role-button",
+                    title: "Open a copy of this guide in the creator",
+                    onclick: {
+                        let mut fork = build.clone();
+                        fork.title = Some(match &build.title {
+                            Some(title) => format!("{} (fork)", title),
+                            None => format!("{} {} (fork)", GODS[&god_name].display_name, build.role),
+                        });
+                        // The fork route asks before replacing an unsaved draft
+                        move |_| {
+                            navigator().push(Route::GuideFork { code: encode_guide(&fork) });
+                        }
+                    },
+                    "Fork"
+                }
+                button {
+                    class: "role-button",
+                    title: "Put this guide next to another one",
+                    onclick: {
+                        let left = entry.id.clone();
+                        move |_| {
+                            navigator().push(Route::Compare { left: left.clone(), right: String::new() });
+                        }
+                    },
+                    "Compare"
+                }
+                ShareButton { guide: build.clone() }
             }
         }
+        GuideContent { guide: build.clone() }
+    }
+} 
+
+/// Build, stats, timeline, skill order and strategy of one guide
+#[component]
+pub fn GuideContent(guide: Guide) -> Element {
+    let mut stats_level = use_signal(|| MAX_LEVEL);
+
+    let Some(god_info) = GODS.get(&guide.god_id) else {
+        return rsx! { div { "No god information found" } }
+    };
+    let default_skill_order = SKILL_ORDER.get(&guide.god_id).cloned().unwrap_or_default();
+    // Only worth a notice when something the guide uses changed
+    let stale = impact_since_written(&guide).filter(|(_, impact)| !impact.is_empty());
+
+    rsx! {
         div {
             class: "explain-content",
+
+            if let Some((patch, impact)) = stale {
+                div {
+                    class: "patch-notice",
+                    p {
+                        strong { "Changed since patch {patch}: " }
+                        if guide.patch.is_some() {
+                            "this guide was written for {patch}, the data is now on {current_patch().id}. "
+                        } else {
+                            "this guide doesn't say which patch it was written for, so it's compared with the oldest one bundled. "
+                        }
+                        Link {
+                            to: Route::PatchNotes { from: patch.to_string(), to: current_patch().id.to_string() },
+                            "All patch changes"
+                        }
+                    }
+                    GuideImpactList { impact }
+                }
+            }
             
             // Build and relics in same row
             div {
@@ -295,7 +406,7 @@ pub fn Explain() -> Element {
                     div {
                         class: "build-items-container",
                         style: "overflow-x: auto; overflow-y: hidden;",
-                        {render_item_row(&build.build, None)}
+                        {render_item_row(&guide.build, None)}
                     }
                 }
                 
@@ -303,17 +414,54 @@ pub fn Explain() -> Element {
                 div {
                     style: "flex: 0 0 auto;",
                     h5 { "Relics" }
-                    {render_item_row(&build.relics, None)}
+                    {render_item_row(&guide.relics, None)}
+                }
+            }
+
+            h5 { "Build Stats" }
+            {
+                let build_stats = BuildStats::from_guide(&guide);
+                let god_stats = god_info.stats_at_level(stats_level()).map(|stats| stats.with_build(&build_stats));
+
+                rsx! {
+                    div {
+                        class: "stats-level",
+                        span { "Level {stats_level}" }
+                        input {
+                            r#type: "range",
+                            min: "1",
+                            max: "{MAX_LEVEL}",
+                            value: "{stats_level}",
+                            oninput: move |evt| {
+                                if let Ok(level) = evt.value().parse::<u8>() {
+                                    stats_level.set(level);
+                                }
+                            },
+                        }
+                    }
+                    if let Some(god_stats) = god_stats {
+                        GodStatsTable { stats: god_stats }
+                    } else {
+                        p {
+                            class: "stats-missing",
+                            "No base stats for {god_info.display_name} yet, so only item stats are shown. Ability numbers leave out scalings on health and basic attacks."
+                        }
+                    }
+                    div { class: "build-stats-divider" }
+                    BuildStatsTable {
+                        key: "{guide.role}_{guide.god_id}_stats",
+                        stats: build_stats
+                    }
                 }
             }
 
             h5 { "Timeline" }
             div {
-                key: "{build.role}_{god_name}_timeline",
+                key: "{guide.role}_{guide.god_id}_timeline",
                 class: "timeline",
-                for (i, entry) in build.timeline.iter().enumerate() {
+                for (i, entry) in guide.timeline.iter().enumerate() {
                     TimelinePiece { 
-                        key: "{build.role}_{god_name}_{i}_{entry.percent}", 
+                        key: "{guide.role}_{guide.god_id}_{i}_{entry.percent}", 
                         entry: entry.clone() 
                     }
                 }
@@ -323,19 +471,21 @@ pub fn Explain() -> Element {
             h5 { "Skill Order" }
             {
                 // Use build-specific skill order if available, otherwise fall back to default
-                let skill_order_to_use = if !build.skill_order.is_empty() {
-                    &build.skill_order
+                let skill_order_to_use = if !guide.skill_order.is_empty() {
+                    &guide.skill_order
                 } else {
                     &default_skill_order
                 };
+                let attacker = Attacker::new(god_info, stats_level(), BuildStats::from_guide(&guide));
+                let ability_numbers = god_ability_numbers(god_info, skill_order_to_use, &attacker);
                 
                 rsx! {
                     div {
-                        key: "{build.role}_{god_name}",
+                        key: "{guide.role}_{guide.god_id}",
                         class: "grid_hold",
                         // Show only active abilities (with skill points) - skip passive (index 0)
                         for (skill_idx, ability) in god_info.abilities.iter().enumerate().skip(1) {
-                            {skill_row(ability, skill_order_to_use, skill_idx - 1)}
+                            {skill_row(ability, skill_order_to_use, skill_idx - 1, ability_numbers.get(skill_idx - 1).cloned(), &[])}
                         }
                     }
                 }
@@ -343,7 +493,7 @@ pub fn Explain() -> Element {
 
             
             // Strategy Guide section if available
-            if let Some(strategy) = build.strategy.as_ref() {
+            if let Some(strategy) = guide.strategy.as_ref() {
                 if !strategy.is_empty() {
                     h5 { "Strategy Guide" }
                     div {
@@ -354,4 +504,4 @@ pub fn Explain() -> Element {
             }
         }
     }
-} 
\ No newline at end of file
+}
\ No newline at end of file
@@ -2,8 +2,8 @@
 use dioxus::prelude::*;
 use crate::{SelectedGod, FilteredRole, FilteredClass};
 use crate::data::gods::GODS;
-use crate::data::guides::GUIDES;
-use crate::data::aliases::resolve_role_alias;
+use crate::data::guide_sources::{all_guides, has_role};
+use crate::data::saved_guides::SavedGuides;
 use crate::utils::format_god_image_name;
 use wasm_bindgen::{JsValue, JsCast, closure::Closure};
 use web_sys::window;
@@ -13,6 +13,7 @@ pub fn GodGrid() -> Element {
     let mut god = use_context::<Signal<SelectedGod>>();
     let role = use_context::<Signal<FilteredRole>>();
     let class = use_context::<Signal<FilteredClass>>();
+    let saved_guides = use_context::<Signal<SavedGuides>>();
     
     // Set up keyboard event listener to jump to god on letter press
     use_effect(move || {
@@ -68,6 +69,9 @@ pub fn GodGrid() -> Element {
         Box::leak(Box::new(listener));
     });
     
+    // Curated and locally saved guides
+    let guides = all_guides(&saved_guides.read().0);
+
     // Load and filter gods data
     let filtered_gods: Vec<_> = {
         let separator = "-".repeat(30);
@@ -78,12 +82,9 @@ pub fn GodGrid() -> Element {
                 
                 // Check if god has any builds for the selected role
                 let role_matches = role.read().0.as_ref().map_or(true, |r| {
-                    let resolved_role = resolve_role_alias(r);
                     // Check if this god has any guides for the selected role
-                    GUIDES.get(name)
-                        .map(|guides| {
-                            guides.iter().any(|g| g.role == resolved_role)
-                        })
+                    guides.get(name)
+                        .map(|entries| has_role(entries, r))
                         .unwrap_or(false)
                 });
                 
@@ -99,7 +100,7 @@ pub fn GodGrid() -> Element {
                     web_sys::console::log_1(&JsValue::from_str(&message));
                 }
                 
-                let has_guides = GUIDES.get(name).is_some();
+                let has_guides = guides.contains_key(name);
                 (name.clone(), god_info.clone(), class_matches, role_matches, has_guides)
             })
             .collect::<Vec<_>>()
@@ -0,0 +1,42 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use crate::components::{Ability, Item};
+use crate::data::abilities::ABILITIES;
+use crate::data::items::ITEMS;
+use crate::data::staleness::{Affected, GuideImpact};
+
+fn references(affected: &Affected) -> String {
+    affected.references.iter().map(|reference| reference.label()).collect::<Vec<_>>().join(", ")
+}
+
+fn status(affected: &Affected) -> &'static str {
+    if affected.removed { "removed" } else { "changed" }
+}
+
+/// Items and abilities a patch touched in a guide, with where the guide uses them
+#[component]
+pub fn GuideImpactList(impact: GuideImpact) -> Element {
+    rsx! {
+        ul {
+            class: "guide-impact",
+            for affected in impact.items.iter() {
+                li {
+                    key: "item-{affected.id}",
+                    Item { item: affected.id.clone(), size: 24 }
+                    span { {ITEMS.get(&affected.id).map(|item| item.display_name.clone()).unwrap_or_else(|| affected.id.clone())} }
+                    span { class: "guide-impact-status {status(affected)}", "{status(affected)}" }
+                    span { class: "guide-impact-where", "in {references(affected)}" }
+                }
+            }
+            for affected in impact.abilities.iter() {
+                li {
+                    key: "ability-{affected.id}",
+                    Ability { ab: affected.id.clone(), size: 24 }
+                    span { {ABILITIES.get(&affected.id).map(|ability| ability.display_name.clone()).unwrap_or_else(|| affected.id.clone())} }
+                    span { class: "guide-impact-status {status(affected)}", "{status(affected)}" }
+                    span { class: "guide-impact-where", "in {references(affected)}" }
+                }
+            }
+        }
+    }
+}
@@ -1,14 +1,17 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 use dioxus_router::prelude::{Link, use_route};
-use crate::components::ThemeToggle;
+use crate::components::{CommandPalette, ThemeToggle};
 use crate::Route;
 
 #[component]
 pub fn Header() -> Element {
     let route = use_route::<Route>();
-    let is_home = matches!(route, Route::Home);
-    let is_cheatsheet = matches!(route, Route::Cheatsheet);
+    let is_home = matches!(route, Route::Browse { .. } | Route::GodPage { .. } | Route::GodRolePage { .. });
+    let is_cheatsheet = matches!(route, Route::Cheatsheet { .. });
+    let is_guides = matches!(route, Route::MyGuides | Route::SavedGuideView { .. });
+    let is_items = matches!(route, Route::Items { .. });
+    let is_patch_notes = matches!(route, Route::PatchNotes { .. });
     
     rsx! {
         nav {
@@ -24,6 +27,22 @@ pub fn Header() -> Element {
                 class: if is_cheatsheet { "active" } else { "" },
                 h5 { "Cheatsheet" }
             }
+            Link {
+                to: "/items",
+                class: if is_items { "active" } else { "" },
+                h5 { "Items" }
+            }
+            Link {
+                to: "/patch-notes",
+                class: if is_patch_notes { "active" } else { "" },
+                h5 { "Patch Notes" }
+            }
+            Link { 
+                to: "/guides",
+                class: if is_guides { "active" } else { "" },
+                h5 { "My Guides" }
+            }
+            CommandPalette {}
             ThemeToggle {}
         }
     }
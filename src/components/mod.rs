@@ -15,6 +15,11 @@ pub mod text_with_icons;
 pub mod no_build_cta;
 pub mod scroll_to_top;
 pub mod markdown_renderer;
+pub mod build_stats;
+pub mod share_button;
+pub mod command_palette;
+pub mod guide_impact;
+pub mod data_errors;
 
 pub use ability::*;
 pub use class_filters::*;
@@ -31,4 +36,9 @@ pub use dark_mode_toggle::*;
 pub use text_with_icons::*;
 pub use no_build_cta::*;
 pub use scroll_to_top::*;
-pub use markdown_renderer::*;
\ No newline at end of file
+pub use markdown_renderer::*;
+pub use build_stats::*;
+pub use share_button::*;
+pub use command_palette::*;
+pub use guide_impact::*;
+pub use data_errors::*;
\ No newline at end of file
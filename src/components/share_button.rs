@@ -0,0 +1,38 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use crate::data::guides::Guide;
+use crate::data::share::encode_guide;
+use crate::utils::{absolute_url, copy_to_clipboard};
+use crate::Route;
+
+/// Copies a `/g/:code` link for the guide
+#[component]
+pub fn ShareButton(guide: Guide) -> Element {
+    // None until clicked, then whether the copy went through
+    let mut copied = use_signal(|| None::<Result<(), String>>);
+
+    rsx! {
+        button {
+            class: "role-button",
+            title: match copied() {
+                Some(Err(e)) => format!("Couldn't copy the link: {}", e),
+                _ => "Copy a link to this guide".to_string(),
+            },
+            onclick: move |_| {
+                let url = absolute_url(&Route::SharedGuide { code: encode_guide(&guide) }.to_string());
+                spawn(async move {
+                    let result = copy_to_clipboard(&url).await;
+                    if let Err(e) = &result {
+                        log::warn!("Couldn't copy {}: {}", url, e);
+                    }
+                    copied.set(Some(result));
+                });
+            },
+            match copied() {
+                Some(Ok(())) => "Link copied",
+                Some(Err(_)) => "Copy failed",
+                None => "Share",
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 use crate::components::{Item, Ability};
-use crate::data::aliases::{ITEM_ALIASES, ABILITY_ALIASES};
+use crate::data::aliases::{mention_key, ITEM_ALIASES, ABILITY_ALIASES};
 use std::collections::HashSet;
 use once_cell::sync::Lazy;
 
@@ -65,7 +65,7 @@ pub fn parse_text_with_icons(text: &str, options: IconDisplayOptions) -> Element
                 let i = index;
                 
                 // Clean the word of punctuation for matching
-                let clean_word = word.trim_matches(|c: char| !c.is_alphanumeric() && c != '_' && c != '-').to_lowercase();
+                let clean_word = mention_key(word);
                 let prefix_punct = word.chars().take_while(|c| !c.is_alphanumeric() && *c != '_' && *c != '-').collect::<String>();
                 let suffix_punct = word.chars().rev().take_while(|c| !c.is_alphanumeric() && *c != '_' && *c != '-').collect::<String>().chars().rev().collect::<String>();
                 
@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 use crate::{data::items::{ITEMS, Effect}, TooltipPos};
+use crate::data::item_tree::{builds_into, total_cost, upgrade_cost};
 use crate::HoveredItem;
 
 #[component]
@@ -13,19 +14,34 @@ pub fn Tooltip() -> Element {
         return rsx! { div {} };
     };
 
-    let Some(item) = ITEMS.get(item_id) else {
+    if !ITEMS.contains_key(item_id) {
         return rsx! { div {} };
-    };
+    }
 
     rsx! {
         div {
             class: "tooltip",
             style: "left: {mouse_pos.read().x + 15}px; top: {mouse_pos.read().y + 25}px; pointer-events: none;",
+            ItemCard { item_id: item_id.clone() }
+        }
+    }
+}
+
+/// Name, price, stats and effects of an item, as shown in its tooltip
+#[component]
+pub fn ItemCard(item_id: String) -> Element {
+    let Some(item) = ITEMS.get(&item_id) else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div {
+            class: "item-card",
             div {
                 class: "tooltip-header",
                 h3 { "{item.display_name}" }
-                if item.price > 0 {
-                    span { class: "price", "{item.price}" }
+                if total_cost(&item_id) > 0 {
+                    span { class: "price", "{total_cost(&item_id)}" }
                 }
             }
             
@@ -127,6 +143,39 @@ pub fn Tooltip() -> Element {
                     }
                 }
             }
+
+            // Build tree
+            if !item.builds_from.is_empty() {
+                div {
+                    class: "item-tree-row",
+                    span { class: "label", "BUILDS FROM" }
+                    for component in item.builds_from.iter() {
+                        ItemTreeEntry { key: "{component}", item_id: component.clone() }
+                    }
+                    span { class: "price", "+{upgrade_cost(&item_id)}" }
+                }
+            }
+            if !builds_into(&item_id).is_empty() {
+                div {
+                    class: "item-tree-row",
+                    span { class: "label", "BUILDS INTO" }
+                    for upgrade in builds_into(&item_id) {
+                        ItemTreeEntry { key: "{upgrade}", item_id: upgrade.clone() }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ItemTreeEntry(item_id: String) -> Element {
+    let name = ITEMS.get(&item_id).map(|item| item.display_name.clone()).unwrap_or_else(|| item_id.clone());
+    rsx! {
+        span {
+            class: "item-tree-entry",
+            img { src: "/assets/items/{item_id}.png" }
+            "{name}"
         }
     }
 } 
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use once_cell::sync::Lazy;
+use crate::data::registry::DATA;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Ability {
@@ -9,8 +10,5 @@ pub struct Ability {
     pub details: HashMap<String, serde_json::Value>,
 }
 
-// Load abilities from JSON file
-pub static ABILITIES: Lazy<HashMap<String, Ability>> = Lazy::new(|| {
-    let abilities_json = include_str!("json/abilities.json");
-    serde_json::from_str(abilities_json).expect("Failed to parse abilities.json")
-});
\ No newline at end of file
+// Abilities from abilities.json, empty if it didn't load
+pub static ABILITIES: Lazy<&HashMap<String, Ability>> = Lazy::new(|| &DATA.abilities);
\ No newline at end of file
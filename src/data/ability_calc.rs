@@ -0,0 +1,261 @@
+use crate::data::build_stats::BuildStats;
+use crate::data::formulas::{RankedValue, ScalingStat, Unit, ABILITY_FORMULAS};
+use crate::data::gods::{God, GodStats, MAX_LEVEL};
+use crate::data::items::ItemStat;
+
+/// Detail keys that mention damage but describe a modifier rather than a hit
+const NON_DAMAGE_KEYS: [&str; 5] = ["mitigation", "reduction", "increase", "taken", "reflect"];
+
+/// Share of Magical Power that magical gods add to their basic attacks
+const MAGICAL_BASIC_ATTACK_RATIO: f64 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageType {
+    Physical,
+    Magical,
+}
+
+impl DamageType {
+    /// Mages and guardians deal magical damage, everyone else physical
+    pub fn for_god(god: &God) -> Self {
+        match god.class.to_lowercase().as_str() {
+            "mage" | "guardian" => DamageType::Magical,
+            _ => DamageType::Physical,
+        }
+    }
+
+    pub fn power_stat(&self) -> ItemStat {
+        match self {
+            DamageType::Physical => ItemStat::PhysicalPower,
+            DamageType::Magical => ItemStat::MagicalPower,
+        }
+    }
+}
+
+/// Ranks in each of the four active abilities after `level` points of a skill order
+pub fn ranks_at_level(skill_order: &[u8], level: u8) -> [u8; 4] {
+    let mut ranks = [0; 4];
+    for &skill in skill_order.iter().take(level.min(MAX_LEVEL) as usize) {
+        if (1..=4).contains(&skill) {
+            ranks[skill as usize - 1] += 1;
+        }
+    }
+    ranks
+}
+
+/// Everything the calculator needs to know about the attacker at one point in the game
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attacker {
+    pub level: u8,
+    pub damage_type: DamageType,
+    pub stats: Option<GodStats>, // None when gods.json has no base stats for the god
+    pub build: BuildStats,
+}
+
+impl Attacker {
+    pub fn new(god: &God, level: u8, build: BuildStats) -> Self {
+        Attacker {
+            level: level.clamp(1, MAX_LEVEL),
+            damage_type: DamageType::for_god(god),
+            stats: god.stats_at_level(level).map(|stats| stats.with_build(&build)),
+            build,
+        }
+    }
+
+    /// Physical or Magical Power from items, whichever the god uses
+    pub fn power(&self) -> f64 {
+        self.build.get(&self.damage_type.power_stat()) as f64
+    }
+
+    /// Damage of a single basic attack before mitigation; needs base stats
+    pub fn basic_attack_power(&self) -> Option<f64> {
+        let power_ratio = match self.damage_type {
+            DamageType::Physical => 1.0,
+            DamageType::Magical => MAGICAL_BASIC_ATTACK_RATIO,
+        };
+        Some(self.stats?.basic_attack_damage + self.power() * power_ratio)
+    }
+
+    fn scaling_source(&self, stat: ScalingStat) -> Option<f64> {
+        match stat {
+            ScalingStat::PhysicalPower => Some(self.build.get(&ItemStat::PhysicalPower) as f64),
+            ScalingStat::MagicalPower => Some(self.build.get(&ItemStat::MagicalPower) as f64),
+            ScalingStat::BasicAttackPower => self.basic_attack_power(),
+            ScalingStat::MaxHealth => self.stats.map(|stats| stats.health),
+            ScalingStat::Level => Some(self.level as f64),
+        }
+    }
+
+    /// Evaluate a ranked value: base at rank plus every scaling. `None` when a
+    /// scaling needs base stats the god doesn't have.
+    pub fn evaluate(&self, value: &RankedValue, rank: u8) -> Option<(f64, f64)> {
+        let base = value.at_rank(rank);
+        let bonus = value.scalings.iter()
+            .map(|(stat, ratio)| Some(ratio * self.scaling_source(*stat)?))
+            .sum::<Option<f64>>()?;
+        Some((base, bonus))
+    }
+
+    /// Cooldown after the build's (capped) cooldown reduction
+    pub fn cooldown(&self, base: f64) -> f64 {
+        base * (1.0 - self.build.get(&ItemStat::CooldownReduction) as f64 / 100.0)
+    }
+}
+
+/// One damage figure of an ability, e.g. "damage" or "damage_per_tick"
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamageLine {
+    pub key: String,
+    pub base: f64,
+    pub bonus: f64,
+    pub scalings: Vec<(ScalingStat, f64)>,
+    pub note: Option<String>,
+}
+
+impl DamageLine {
+    pub fn total(&self) -> f64 {
+        self.base + self.bonus
+    }
+}
+
+/// Computed numbers for one active ability
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilityNumbers {
+    pub ability: String,
+    pub rank: u8,
+    pub damage: Vec<DamageLine>,
+    pub base_cooldown: Option<f64>,
+    pub cooldown: Option<f64>,
+    pub cost: Option<f64>,
+}
+
+impl AbilityNumbers {
+    /// The main damage figure: "damage" when present, otherwise the first damage line
+    pub fn primary_damage(&self) -> Option<&DamageLine> {
+        self.damage.iter()
+            .find(|line| line.key == "damage")
+            .or_else(|| self.damage.first())
+    }
+}
+
+/// Numbers for one ability at a rank; rank 0 is reported with rank 1 values
+pub fn ability_numbers(ability: &str, rank: u8, attacker: &Attacker) -> AbilityNumbers {
+    let formulas = ABILITY_FORMULAS.get(ability);
+    let lookup = |key: &str| formulas.and_then(|f| f.get(key));
+
+    let damage = formulas
+        .map(|f| {
+            f.values.iter()
+                .filter(|(key, value)| {
+                    key.contains("damage")
+                        && value.unit != Unit::Percent
+                        && !NON_DAMAGE_KEYS.iter().any(|k| key.contains(k))
+                })
+                .filter_map(|(key, value)| {
+                    let (base, bonus) = attacker.evaluate(value, rank)?;
+                    Some(DamageLine {
+                        key: key.clone(),
+                        base,
+                        bonus,
+                        scalings: value.scalings.clone(),
+                        note: value.note.clone(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let base_cooldown = lookup("cooldown").map(|cd| cd.at_rank(rank));
+
+    AbilityNumbers {
+        ability: ability.to_string(),
+        rank,
+        damage,
+        base_cooldown,
+        cooldown: base_cooldown.map(|cd| attacker.cooldown(cd)),
+        cost: lookup("cost").filter(|c| c.unit == Unit::Mana || c.unit == Unit::None).map(|c| c.at_rank(rank)),
+    }
+}
+
+/// Numbers for every active ability of a god at a level, following a skill order
+pub fn god_ability_numbers(god: &God, skill_order: &[u8], attacker: &Attacker) -> Vec<AbilityNumbers> {
+    let ranks = ranks_at_level(skill_order, attacker.level);
+    god.abilities.iter()
+        .skip(1) // passive
+        .take(4)
+        .zip(ranks)
+        .map(|(ability, rank)| ability_numbers(ability, rank, attacker))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::gods::{test_god, GODS};
+
+    const AGNI_ORDER: [u8; 20] = [3, 2, 2, 1, 4, 2, 2, 3, 4, 2, 3, 3, 4, 3, 1, 1, 4, 1, 1, 4];
+
+    #[test]
+    fn test_ranks_at_level() {
+        assert_eq!(ranks_at_level(&AGNI_ORDER, 1), [0, 0, 1, 0]);
+        assert_eq!(ranks_at_level(&AGNI_ORDER, 5), [1, 2, 1, 1]);
+        assert_eq!(ranks_at_level(&AGNI_ORDER, 20), [5, 5, 5, 5]);
+        assert_eq!(ranks_at_level(&AGNI_ORDER, 30), [5, 5, 5, 5]);
+        assert_eq!(ranks_at_level(&[], 10), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_agni_flame_wave_damage() {
+        let agni = GODS.get("agni").unwrap();
+        let build = BuildStats::from_items(["spear_of_the_magus"]);
+        let power = build.get(&ItemStat::MagicalPower) as f64;
+        let attacker = Attacker::new(agni, 12, build);
+
+        let numbers = god_ability_numbers(agni, &AGNI_ORDER, &attacker);
+        assert_eq!(numbers.len(), 4);
+
+        let flame_wave = numbers.iter().find(|n| n.ability == "flame_wave").unwrap();
+        assert_eq!(flame_wave.rank, 5);
+
+        let formula = ABILITY_FORMULAS.get("flame_wave").unwrap().get("damage").unwrap();
+        let (_, ratio) = formula.scalings.iter().find(|(stat, _)| *stat == ScalingStat::MagicalPower).unwrap();
+        let damage = flame_wave.damage.iter().find(|d| d.key == "damage").unwrap();
+        assert_eq!(damage.base, formula.at_rank(5));
+        assert!((damage.total() - (formula.at_rank(5) + ratio * power)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_missing_base_stats() {
+        let agni = GODS.get("agni").unwrap();
+        assert!(agni.stats.is_none());
+        let attacker = Attacker::new(agni, 20, BuildStats::from_items(["spear_of_the_magus"]));
+        assert_eq!(attacker.basic_attack_power(), None);
+
+        let power = RankedValue { per_rank: vec![100.0], scalings: vec![(ScalingStat::MagicalPower, 0.5)], unit: Unit::None, note: None };
+        let health = RankedValue { scalings: vec![(ScalingStat::MaxHealth, 0.1)], ..power.clone() };
+        assert!(attacker.evaluate(&power, 1).is_some());
+        assert_eq!(attacker.evaluate(&health, 1), None);
+
+        let attacker = Attacker::new(&test_god("agni"), 20, BuildStats::default());
+        assert_eq!(attacker.evaluate(&health, 1), Some((100.0, 0.1 * attacker.stats.unwrap().health)));
+    }
+
+    #[test]
+    fn test_cooldown_reduction_is_capped() {
+        let agni = GODS.get("agni").unwrap();
+        let cdr_items: Vec<_> = crate::data::items::ITEMS.iter()
+            .filter(|(_, item)| item.stats.get(&ItemStat::CooldownReduction).is_some_and(|v| *v >= 20))
+            .map(|(name, _)| name.clone())
+            .take(3)
+            .collect();
+        let attacker = Attacker::new(agni, 20, BuildStats::from_items(&cdr_items));
+
+        assert!((attacker.cooldown(10.0) - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_damage_type_by_class() {
+        assert_eq!(DamageType::for_god(GODS.get("agni").unwrap()), DamageType::Magical);
+        assert_eq!(DamageType::for_god(GODS.get("achilles").unwrap()), DamageType::Physical);
+    }
+}
@@ -1,17 +1,15 @@
 use std::collections::HashMap;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use crate::data::registry::DATA;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Aliases {
     pub abilities: HashMap<String, Vec<String>>,
     pub items: HashMap<String, Vec<String>>,
 }
 
-pub static ALIASES: Lazy<Aliases> = Lazy::new(|| {
-    let aliases_json = include_str!("json/aliases.json");
-    serde_json::from_str(aliases_json).expect("Failed to parse aliases.json")
-});
+pub static ALIASES: Lazy<&Aliases> = Lazy::new(|| &DATA.aliases);
 
 // Create reverse lookup maps for quick alias resolution
 pub static ITEM_ALIASES: Lazy<HashMap<String, String>> = Lazy::new(|| {
@@ -47,6 +45,33 @@ pub fn resolve_ability_alias(name: &str) -> String {
         .unwrap_or_else(|| name.to_string())
 }
 
+/// A word of free text as `TextWithIcons` matches it against the aliases:
+/// lowercase, without surrounding punctuation
+pub fn mention_key(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric() && c != '_' && c != '-').to_lowercase()
+}
+
+/// Canonical IDs of the items and abilities free text gets icons for, in the
+/// order they first appear
+pub fn text_mentions(text: &str) -> (Vec<String>, Vec<String>) {
+    let mut items: Vec<String> = Vec::new();
+    let mut abilities: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        let key = mention_key(word);
+        let (found, ids) = match ITEM_ALIASES.get(&key) {
+            Some(item) => (item, &mut items),
+            None => match ABILITY_ALIASES.get(&key) {
+                Some(ability) => (ability, &mut abilities),
+                None => continue,
+            },
+        };
+        if !ids.contains(found) {
+            ids.push(found.clone());
+        }
+    }
+    (items, abilities)
+}
+
 // Role aliases map
 pub static ROLE_ALIASES: Lazy<HashMap<String, String>> = Lazy::new(|| {
     let mut map = HashMap::new();
@@ -74,4 +99,9 @@ pub fn resolve_role_alias(role: &str) -> String {
     ROLE_ALIASES.get(&role.to_lowercase())
         .cloned()
         .unwrap_or_else(|| role.to_string())
-} 
\ No newline at end of file
+} 
+
+/// Whether two role names mean the same role, e.g. "adc" and "Carry"
+pub fn same_role(a: &str, b: &str) -> bool {
+    resolve_role_alias(a).eq_ignore_ascii_case(&resolve_role_alias(b))
+}
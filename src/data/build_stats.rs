@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+use crate::data::aliases::resolve_item_alias;
+use crate::data::guides::Guide;
+use crate::data::items::{ItemStat, ITEMS};
+
+/// Maximum attacks per second any god can reach
+pub const ATTACK_SPEED_CAP: f64 = 2.5;
+
+/// Hard cap the game applies to a summed stat, if any
+pub fn stat_cap(stat: &ItemStat) -> Option<i32> {
+    match stat {
+        ItemStat::CooldownReduction => Some(40),
+        ItemStat::CriticalStrikeChance => Some(100),
+        ItemStat::PhysicalPenetration | ItemStat::MagicalPenetration => Some(50),
+        ItemStat::PhysicalPenetrationPercent | ItemStat::MagicalPenetrationPercent => Some(40),
+        _ => None,
+    }
+}
+
+/// One row of the totals table
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatTotal {
+    pub stat: ItemStat,
+    pub raw: i32,
+    pub value: i32,
+}
+
+impl StatTotal {
+    /// True when the build stacks more of this stat than the cap allows
+    pub fn is_capped(&self) -> bool {
+        self.raw > self.value
+    }
+}
+
+/// Summed item stats for a full build
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BuildStats {
+    totals: BTreeMap<ItemStat, i32>,
+    pub items: Vec<String>,         // resolved item IDs that contributed
+    pub unknown_items: Vec<String>, // IDs that didn't resolve to anything in ITEMS
+}
+
+impl BuildStats {
+    /// Aggregate the build and relics of a guide
+    pub fn from_guide(guide: &Guide) -> Self {
+        Self::from_items(guide.build.iter().chain(guide.relics.iter()))
+    }
+
+    /// Aggregate any list of item IDs (aliases are resolved)
+    pub fn from_items<I, S>(items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut stats = BuildStats::default();
+
+        for name in items {
+            let name = name.as_ref();
+            let resolved = resolve_item_alias(name);
+            let Some(item) = ITEMS.get(&resolved) else {
+                stats.unknown_items.push(name.to_string());
+                continue;
+            };
+
+            for (stat, value) in &item.stats {
+                *stats.totals.entry(stat.clone()).or_insert(0) += value;
+            }
+            stats.items.push(resolved);
+        }
+
+        stats
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// Uncapped sum of a stat
+    pub fn raw(&self, stat: &ItemStat) -> i32 {
+        self.totals.get(stat).copied().unwrap_or(0)
+    }
+
+    /// Sum of a stat after the game's caps
+    pub fn get(&self, stat: &ItemStat) -> i32 {
+        let raw = self.raw(stat);
+        match stat_cap(stat) {
+            Some(cap) => raw.min(cap),
+            None => raw,
+        }
+    }
+
+    /// Attacks per second from a base rate plus the build's attack speed,
+    /// held at `ATTACK_SPEED_CAP`
+    pub fn attack_speed(&self, base: f64) -> f64 {
+        (base * (1.0 + self.get(&ItemStat::AttackSpeed) as f64 / 100.0)).min(ATTACK_SPEED_CAP)
+    }
+
+    /// Totals table in `ItemStat` order
+    pub fn totals(&self) -> Vec<StatTotal> {
+        self.totals
+            .keys()
+            .map(|stat| StatTotal {
+                stat: stat.clone(),
+                raw: self.raw(stat),
+                value: self.get(stat),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::guides::GUIDES;
+
+    #[test]
+    fn test_sums_item_stats() {
+        let stats = BuildStats::from_items(["the_crusher", "katana"]);
+        assert_eq!(stats.raw(&ItemStat::PhysicalPower), 42);
+        assert_eq!(stats.raw(&ItemStat::AttackSpeed), 25);
+        assert_eq!(stats.raw(&ItemStat::MovementSpeed), 3);
+        assert_eq!(stats.raw(&ItemStat::MagicalPower), 0);
+        assert!(stats.unknown_items.is_empty());
+    }
+
+    #[test]
+    fn test_resolves_aliases_and_reports_unknown() {
+        let stats = BuildStats::from_items(["tahuti", "not_a_real_item"]);
+        assert_eq!(stats.items, vec!["rod_of_tahuti".to_string()]);
+        assert_eq!(stats.unknown_items, vec!["not_a_real_item".to_string()]);
+    }
+
+    #[test]
+    fn test_applies_caps() {
+        let cdr_items: Vec<_> = ITEMS.iter()
+            .filter(|(_, item)| item.stats.get(&ItemStat::CooldownReduction).is_some_and(|v| *v >= 20))
+            .map(|(name, _)| name.clone())
+            .take(3)
+            .collect();
+        let stats = BuildStats::from_items(&cdr_items);
+
+        assert!(stats.raw(&ItemStat::CooldownReduction) > 40);
+        assert_eq!(stats.get(&ItemStat::CooldownReduction), 40);
+        let row = stats.totals().into_iter().find(|t| t.stat == ItemStat::CooldownReduction).unwrap();
+        assert!(row.is_capped());
+    }
+
+    #[test]
+    fn test_caps_attack_speed() {
+        let stats = BuildStats::from_items(["the_crusher", "katana"]);
+        assert!((stats.attack_speed(1.0) - 1.25).abs() < 1e-9);
+
+        let stats = BuildStats::from_items(["the_crusher"; 6]);
+        assert_eq!(stats.attack_speed(1.0), ATTACK_SPEED_CAP);
+    }
+
+    #[test]
+    fn test_every_guide_aggregates() {
+        for guide in GUIDES.values().flatten() {
+            let stats = BuildStats::from_guide(guide);
+            assert!(!stats.is_empty(), "{} {} has no stats", guide.god_id, guide.role);
+        }
+    }
+}
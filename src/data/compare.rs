@@ -0,0 +1,108 @@
+use crate::data::aliases::resolve_item_alias;
+use crate::data::build_stats::BuildStats;
+use crate::data::items::ItemStat;
+
+/// How two item lists overlap. Items keep the order they have in their guide.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ItemDiff {
+    pub shared: Vec<String>,
+    pub only_left: Vec<String>,
+    pub only_right: Vec<String>,
+}
+
+/// Split two item lists into shared and one-sided items (aliases count as the same item)
+pub fn diff_items(left: &[String], right: &[String]) -> ItemDiff {
+    let left_ids: Vec<String> = left.iter().map(|item| resolve_item_alias(item)).collect();
+    let right_ids: Vec<String> = right.iter().map(|item| resolve_item_alias(item)).collect();
+
+    let mut diff = ItemDiff::default();
+    for (item, id) in left.iter().zip(&left_ids) {
+        if right_ids.contains(id) {
+            diff.shared.push(item.clone());
+        } else {
+            diff.only_left.push(item.clone());
+        }
+    }
+    for (item, id) in right.iter().zip(&right_ids) {
+        if !left_ids.contains(id) {
+            diff.only_right.push(item.clone());
+        }
+    }
+    diff
+}
+
+/// A stat total in both builds
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatDelta {
+    pub stat: ItemStat,
+    pub left: i32,
+    pub right: i32,
+}
+
+impl StatDelta {
+    /// Right minus left
+    pub fn delta(&self) -> i32 {
+        self.right - self.left
+    }
+}
+
+/// Every stat either build has, with capped totals for both sides, in `ItemStat` order
+pub fn stat_deltas(left: &BuildStats, right: &BuildStats) -> Vec<StatDelta> {
+    let mut stats: Vec<ItemStat> = left.totals().into_iter()
+        .chain(right.totals())
+        .map(|total| total.stat)
+        .collect();
+    stats.sort();
+    stats.dedup();
+
+    stats.into_iter()
+        .map(|stat| StatDelta { left: left.get(&stat), right: right.get(&stat), stat })
+        .collect()
+}
+
+/// Levels (0-based) where the two skill orders put the point in different abilities.
+/// A level only one order reaches counts as diverging.
+pub fn skill_divergence(left: &[u8], right: &[u8]) -> Vec<usize> {
+    (0..left.len().max(right.len()))
+        .filter(|&i| left.get(i) != right.get(i))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_diff_items() {
+        let diff = diff_items(
+            &items(&["spear_of_the_magus", "tahuti", "obsidian_shard"]),
+            &items(&["rod_of_tahuti", "spear_of_desolation", "spear_of_the_magus"]),
+        );
+        assert_eq!(diff.shared, items(&["spear_of_the_magus", "tahuti"]));
+        assert_eq!(diff.only_left, items(&["obsidian_shard"]));
+        assert_eq!(diff.only_right, items(&["spear_of_desolation"]));
+    }
+
+    #[test]
+    fn test_stat_deltas_cover_both_builds() {
+        let left = BuildStats::from_items(["rod_of_tahuti"]);
+        let right = BuildStats::from_items(["rod_of_tahuti", "obsidian_shard"]);
+        let deltas = stat_deltas(&left, &right);
+
+        assert!(deltas.iter().all(|delta| delta.delta() >= 0));
+        assert!(deltas.iter().any(|delta| delta.delta() > 0));
+        assert_eq!(deltas.len(), right.totals().len());
+        assert_eq!(stat_deltas(&left, &left).iter().map(StatDelta::delta).sum::<i32>(), 0);
+    }
+
+    #[test]
+    fn test_skill_divergence() {
+        assert_eq!(skill_divergence(&[1, 2, 3, 1], &[1, 3, 3, 1]), vec![1]);
+        assert_eq!(skill_divergence(&[1, 2], &[1, 2, 3]), vec![2]);
+        assert!(skill_divergence(&[], &[]).is_empty());
+    }
+}
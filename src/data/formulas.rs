@@ -0,0 +1,389 @@
+use std::collections::{BTreeMap, HashMap};
+use once_cell::sync::Lazy;
+use crate::data::abilities::{Ability, ABILITIES};
+
+/// Detail keys that hold descriptive text rather than numbers
+const TEXT_KEYS: [&str; 2] = ["ability_type", "notes"];
+
+/// What a scaling percentage is taken from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ScalingStat {
+    PhysicalPower,
+    MagicalPower,
+    BasicAttackPower,
+    MaxHealth,
+    Level,
+}
+
+impl ScalingStat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ScalingStat::PhysicalPower => "Physical Power",
+            ScalingStat::MagicalPower => "Magical Power",
+            ScalingStat::BasicAttackPower => "Basic Attack Power",
+            ScalingStat::MaxHealth => "Max Health",
+            ScalingStat::Level => "Level",
+        }
+    }
+
+    fn from_phrase(phrase: &str) -> Option<Self> {
+        let phrase = phrase.trim().to_lowercase();
+        if phrase.starts_with("physical power") {
+            Some(ScalingStat::PhysicalPower)
+        } else if phrase.starts_with("magical power") {
+            Some(ScalingStat::MagicalPower)
+        } else if phrase.starts_with("basic attack power") {
+            Some(ScalingStat::BasicAttackPower)
+        } else if phrase.starts_with("maximum health") || phrase.starts_with("max health") {
+            Some(ScalingStat::MaxHealth)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Unit {
+    #[default]
+    None,
+    Percent,
+    Seconds,
+    Mana,
+}
+
+/// A numeric ability value such as "90/130/170/210/250 (+85% of your Physical Power)"
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RankedValue {
+    pub per_rank: Vec<f64>,
+    /// Scaling ratios; power scalings are fractions (0.85), level scalings are per level amounts
+    pub scalings: Vec<(ScalingStat, f64)>,
+    pub unit: Unit,
+    /// Trailing qualifier we kept but didn't interpret, e.g. "per hit" or "on max Tide"
+    pub note: Option<String>,
+}
+
+impl RankedValue {
+    /// Base value at an ability rank (1-based); single values apply to every rank
+    pub fn at_rank(&self, rank: u8) -> f64 {
+        let index = (rank.max(1) as usize - 1).min(self.per_rank.len().saturating_sub(1));
+        self.per_rank.get(index).copied().unwrap_or(0.0)
+    }
+}
+
+/// A detail value the parser couldn't turn into a `RankedValue`
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnparsedDetail {
+    pub ability: String,
+    pub key: String,
+    pub text: String,
+}
+
+/// All numeric details of one ability, keyed by detail name
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AbilityFormulas {
+    pub values: BTreeMap<String, RankedValue>,
+    pub unparsed: Vec<(String, String)>,
+}
+
+impl AbilityFormulas {
+    pub fn get(&self, key: &str) -> Option<&RankedValue> {
+        self.values.get(key)
+    }
+}
+
+/// Parsed formulas for every ability in `ABILITIES`
+pub static ABILITY_FORMULAS: Lazy<HashMap<String, AbilityFormulas>> = Lazy::new(|| {
+    ABILITIES.iter()
+        .map(|(name, ability)| (name.clone(), parse_ability(ability)))
+        .collect()
+});
+
+/// Every detail string across all abilities that couldn't be parsed, sorted by ability and key.
+/// `cargo test unparsed -- --nocapture` prints it.
+#[cfg(test)]
+pub fn unparsed_report() -> Vec<UnparsedDetail> {
+    let mut report: Vec<UnparsedDetail> = ABILITY_FORMULAS.iter()
+        .flat_map(|(name, formulas)| {
+            formulas.unparsed.iter().map(move |(key, text)| UnparsedDetail {
+                ability: name.clone(),
+                key: key.clone(),
+                text: text.clone(),
+            })
+        })
+        .collect();
+    report.sort_by(|a, b| (&a.ability, &a.key).cmp(&(&b.ability, &b.key)));
+    report
+}
+
+/// Parse every numeric detail of an ability
+pub fn parse_ability(ability: &Ability) -> AbilityFormulas {
+    let mut formulas = AbilityFormulas::default();
+
+    for (key, value) in &ability.details {
+        if TEXT_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+
+        let text = match value {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+
+        // "55/20" under rangeradius is a range and a radius, not two ranks
+        let split_keys = match key.as_str() {
+            "rangeradius" | "rangradius" => Some(("range", "radius")),
+            "radiusrange" => Some(("radius", "range")),
+            _ => None,
+        };
+
+        match (split_keys, parse_ranked_value(&text)) {
+            (Some((first, second)), Some(parsed)) if parsed.per_rank.len() == 2 => {
+                for (k, v) in [(first, parsed.per_rank[0]), (second, parsed.per_rank[1])] {
+                    formulas.values.insert(k.to_string(), RankedValue { per_rank: vec![v], ..Default::default() });
+                }
+            }
+            (Some((first, _)), Some(parsed)) if parsed.per_rank.len() == 1 => {
+                formulas.values.insert(first.to_string(), parsed);
+            }
+            (None, Some(parsed)) => {
+                formulas.values.insert(key.clone(), parsed);
+            }
+            _ => formulas.unparsed.push((key.clone(), text)),
+        }
+    }
+
+    formulas.unparsed.sort();
+    formulas
+}
+
+/// Parse a single detail string, returning `None` if it doesn't start with a number
+pub fn parse_ranked_value(text: &str) -> Option<RankedValue> {
+    let mut rest = text.trim();
+    let mut value = RankedValue::default();
+
+    if rest.eq_ignore_ascii_case("none") {
+        value.per_rank.push(0.0);
+        return Some(value);
+    }
+
+    // Rank values: "90/130/170", "1/1.1/1.2s", ".3", "-40%"; a bare scaling like
+    // "(+5% of your Basic Attack Power)" has no base damage
+    if take_power_scaling(rest).is_some() {
+        value.per_rank.push(0.0);
+    } else {
+        let sign = if rest.starts_with('-') { -1.0 } else { 1.0 };
+        rest = rest.trim_start_matches(['+', '-']);
+        loop {
+            let (number, remaining) = take_number(rest)?;
+            value.per_rank.push(sign * number);
+            rest = remaining;
+            match rest.strip_prefix('/') {
+                Some(remaining) => rest = remaining.trim_start_matches('/'),
+                None => break,
+            }
+        }
+    }
+
+    let (unit, remaining) = take_unit(rest);
+    value.unit = unit;
+    rest = remaining.trim_start();
+
+    // Scalings: "(+85% of your Physical Power)", "+ 2 per level", "(+0.5 per level)"
+    loop {
+        if let Some((scaling, remaining)) = take_power_scaling(rest) {
+            value.scalings.push(scaling);
+            rest = remaining.trim_start();
+        } else if let Some((per_level, remaining)) = take_level_scaling(rest) {
+            value.scalings.push((ScalingStat::Level, per_level));
+            rest = remaining.trim_start();
+        } else {
+            break;
+        }
+    }
+
+    if !rest.is_empty() {
+        value.note = Some(rest.to_string());
+    }
+
+    Some(value)
+}
+
+fn take_number(text: &str) -> Option<(f64, &str)> {
+    let end = text
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_digit() && *c != '.')
+        .map_or(text.len(), |(i, _)| i);
+    let number = text[..end].trim_end_matches('.').parse().ok()?;
+    Some((number, &text[end..]))
+}
+
+fn take_unit(text: &str) -> (Unit, &str) {
+    if let Some(rest) = text.strip_prefix('%') {
+        return (Unit::Percent, rest);
+    }
+
+    let trimmed = text.trim_start();
+    let word_end = trimmed
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(trimmed.len());
+    let unit = match trimmed[..word_end].to_lowercase().as_str() {
+        "s" | "sec" | "second" | "seconds" => Unit::Seconds,
+        "mana" => Unit::Mana,
+        _ => return (Unit::None, text),
+    };
+    (unit, &trimmed[word_end..])
+}
+
+fn take_power_scaling(text: &str) -> Option<((ScalingStat, f64), &str)> {
+    let inner_end = text.find(')')?;
+    let inner = text.strip_prefix('(')?[..inner_end - 1].trim();
+    let inner = inner.strip_prefix('+').unwrap_or(inner).trim_start();
+
+    let (percent, rest) = take_number(inner)?;
+    let rest = rest.strip_prefix('%')?.trim_start();
+    let rest = rest.strip_prefix("of")?.trim_start();
+    let rest = rest.strip_prefix("your").or_else(|| rest.strip_prefix("you"))?;
+    let stat = ScalingStat::from_phrase(rest)?;
+
+    Some(((stat, percent / 100.0), &text[inner_end + 1..]))
+}
+
+fn take_level_scaling(text: &str) -> Option<(f64, &str)> {
+    let (inner, rest) = match text.strip_prefix('(') {
+        Some(inner) => {
+            let end = inner.find(')')?;
+            (&inner[..end], &inner[end + 1..])
+        }
+        None => (text, ""),
+    };
+
+    let after_plus = inner.strip_prefix('+')?.trim_start();
+    let (per_level, after_number) = take_number(after_plus)?;
+    let after_number = after_number.strip_prefix('%').unwrap_or(after_number).trim_start();
+    let lower = after_number.to_ascii_lowercase();
+    let after_per = lower.strip_prefix("per ")?;
+
+    // "per level", "per Level", "per Hera's Level", "per god level"
+    let level_end = after_per.find("level")? + "level".len();
+    if after_per[..level_end].split_whitespace().count() > 2 {
+        return None;
+    }
+
+    let consumed = after_number.len() - after_per.len() + level_end;
+    let remaining = if rest.is_empty() { &after_number[consumed..] } else { rest };
+    Some((per_level, remaining))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_damage_with_scaling() {
+        let value = parse_ranked_value("90/130/170/210/250 (+85% of your Physical Power)").unwrap();
+        assert_eq!(value.per_rank, vec![90.0, 130.0, 170.0, 210.0, 250.0]);
+        assert_eq!(value.scalings, vec![(ScalingStat::PhysicalPower, 0.85)]);
+        assert_eq!(value.unit, Unit::None);
+        assert_eq!(value.note, None);
+        assert_eq!(value.at_rank(3), 170.0);
+        assert_eq!(value.at_rank(9), 250.0);
+    }
+
+    #[test]
+    fn test_parse_units() {
+        let cost = parse_ranked_value("55/60/65/70/75 mana").unwrap();
+        assert_eq!(cost.unit, Unit::Mana);
+        assert_eq!(cost.per_rank.len(), 5);
+
+        let cooldown = parse_ranked_value("14 seconds").unwrap();
+        assert_eq!(cooldown.unit, Unit::Seconds);
+        assert_eq!(cooldown.per_rank, vec![14.0]);
+
+        let root = parse_ranked_value("1/1.2/1.4/1.6/1.8s").unwrap();
+        assert_eq!(root.unit, Unit::Seconds);
+        assert_eq!(root.per_rank[1], 1.2);
+
+        let slow = parse_ranked_value("15/17.5/20/22.5/25%").unwrap();
+        assert_eq!(slow.unit, Unit::Percent);
+    }
+
+    #[test]
+    fn test_parse_level_scaling_and_notes() {
+        let heal = parse_ranked_value("7/10/13/16/19 + 2 per level").unwrap();
+        assert_eq!(heal.scalings, vec![(ScalingStat::Level, 2.0)]);
+        assert_eq!(heal.note, None);
+
+        let protections = parse_ranked_value("4 +.3 per Level").unwrap();
+        assert_eq!(protections.scalings, vec![(ScalingStat::Level, 0.3)]);
+
+        let shield = parse_ranked_value("70/115/160/205/250 +17 per Hera's Level").unwrap();
+        assert_eq!(shield.scalings, vec![(ScalingStat::Level, 17.0)]);
+
+        let broodling = parse_ranked_value("20/30/40/50/60 (+20% of your Physical Power) per hit").unwrap();
+        assert_eq!(broodling.scalings, vec![(ScalingStat::PhysicalPower, 0.2)]);
+        assert_eq!(broodling.note.as_deref(), Some("per hit"));
+    }
+
+    #[test]
+    fn test_parse_data_quirks() {
+        assert_eq!(parse_ranked_value("None").unwrap().per_rank, vec![0.0]);
+
+        let bare = parse_ranked_value("(+5% of your Basic Attack Power)").unwrap();
+        assert_eq!(bare.per_rank, vec![0.0]);
+        assert_eq!(bare.scalings, vec![(ScalingStat::BasicAttackPower, 0.05)]);
+
+        let typo = parse_ranked_value("25/24/23/22//21 seconds").unwrap();
+        assert_eq!(typo.per_rank, vec![25.0, 24.0, 23.0, 22.0, 21.0]);
+
+        assert_eq!(parse_ranked_value("+6/7/8/9/10%").unwrap().per_rank[0], 6.0);
+        assert_eq!(parse_ranked_value("-40%").unwrap().per_rank, vec![-40.0]);
+    }
+
+    #[test]
+    fn test_reject_text() {
+        assert_eq!(parse_ranked_value("Reaching level 5, 10 and 15."), None);
+        assert_eq!(parse_ranked_value(""), None);
+    }
+
+    #[test]
+    fn test_split_range_radius() {
+        let ability: Ability = serde_json::from_str(r#"{
+            "display_name": "Test",
+            "description": "",
+            "details": {"rangeradius": "55/20", "ability_type": "Circle, Damage", "odd": "Up to 10%"}
+        }"#).unwrap();
+        let formulas = parse_ability(&ability);
+
+        assert_eq!(formulas.get("range").unwrap().per_rank, vec![55.0]);
+        assert_eq!(formulas.get("radius").unwrap().per_rank, vec![20.0]);
+        assert_eq!(formulas.unparsed, vec![("odd".to_string(), "Up to 10%".to_string())]);
+    }
+
+    #[test]
+    fn test_core_details_parse_for_every_ability() {
+        // Only free text like "Variable" may be left over for the keys everything else depends on
+        let core_failures: Vec<_> = unparsed_report()
+            .into_iter()
+            .filter(|d| ["damage", "cost", "cooldown"].contains(&d.key.as_str()))
+            .filter(|d| d.text.chars().any(|c| c.is_ascii_digit()))
+            .collect();
+        assert!(core_failures.is_empty(), "unparsed core details: {:#?}", core_failures);
+
+        for (name, formulas) in ABILITY_FORMULAS.iter() {
+            if let Some(damage) = formulas.get("damage") {
+                assert!(!damage.per_rank.is_empty(), "{} has empty damage", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unparsed_report() {
+        let report = unparsed_report();
+        println!("{} ability details could not be parsed", report.len());
+        for detail in &report {
+            println!("{}.{}: {}", detail.ability, detail.key, detail.text);
+        }
+        assert!(report.windows(2).all(|pair| (&pair[0].ability, &pair[0].key) <= (&pair[1].ability, &pair[1].key)));
+    }
+}
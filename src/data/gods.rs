@@ -1,10 +1,80 @@
 use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use once_cell::sync::Lazy;
+use crate::data::build_stats::{BuildStats, ATTACK_SPEED_CAP};
+use crate::data::items::ItemStat;
+use crate::data::registry::DATA;
+
+pub const MAX_LEVEL: u8 = 20;
+
+/// A stat that grows linearly with god level
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct LevelScaling {
+    pub base: f64,
+    #[serde(default)]
+    pub per_level: f64,
+}
+
+impl LevelScaling {
+    pub fn at_level(&self, level: u8) -> f64 {
+        let level = level.clamp(1, MAX_LEVEL);
+        self.base + self.per_level * (level - 1) as f64
+    }
+}
+
+/// Base stats and per-level growth of a god
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct BaseStats {
+    pub health: LevelScaling,
+    pub mana: LevelScaling,
+    pub physical_protection: LevelScaling,
+    pub magical_protection: LevelScaling,
+    pub hp5: LevelScaling,
+    pub mp5: LevelScaling,
+    pub basic_attack_damage: LevelScaling,
+    /// Attacks per second; `per_level` is a percent bonus per level
+    pub attack_speed: LevelScaling,
+    pub movement_speed: f64,
+}
+
+/// Concrete stats of a god at a given level, optionally including items
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GodStats {
+    pub level: u8,
+    pub health: f64,
+    pub mana: f64,
+    pub physical_protection: f64,
+    pub magical_protection: f64,
+    pub hp5: f64,
+    pub mp5: f64,
+    pub basic_attack_damage: f64,
+    pub attack_speed: f64,
+    pub movement_speed: f64,
+}
+
+impl GodStats {
+    /// Add a build's item stats on top of the god's own stats
+    pub fn with_build(mut self, build: &BuildStats) -> Self {
+        let flat = |stat: ItemStat| build.get(&stat) as f64;
+        let percent = |stat: ItemStat| build.get(&stat) as f64 / 100.0;
+
+        self.health += flat(ItemStat::Health);
+        self.mana += flat(ItemStat::Mana);
+        self.physical_protection += flat(ItemStat::PhysicalProtection);
+        self.magical_protection += flat(ItemStat::MagicalProtection);
+        self.hp5 += flat(ItemStat::HP5);
+        self.mp5 += flat(ItemStat::MP5);
+        self.basic_attack_damage += flat(ItemStat::BasicAttackDamage);
+        self.attack_speed = build.attack_speed(self.attack_speed);
+        self.movement_speed *= 1.0 + percent(ItemStat::MovementSpeed);
+        self
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct God {
     pub display_name: String,
+    #[serde(default)] // missing from gods.json before 5.1
     pub class: String,
     pub abilities: Vec<String>,
     #[serde(default)]
@@ -15,10 +85,91 @@ pub struct God {
     pub roles: Vec<String>,
     #[serde(default)]
     pub image_path: String,
+    #[serde(default)]
+    pub stats: Option<BaseStats>,
 }
 
-// Load gods from JSON file
-pub static GODS: Lazy<BTreeMap<String, God>> = Lazy::new(|| {
-    let gods_json = include_str!("json/gods.json");
-    serde_json::from_str(gods_json).expect("Failed to parse gods.json")
-});
\ No newline at end of file
+impl God {
+    /// Stats at a level from 1 to 20, without items. `None` until gods.json
+    /// has base stats for the god.
+    pub fn stats_at_level(&self, level: u8) -> Option<GodStats> {
+        let base = self.stats?;
+        let level = level.clamp(1, MAX_LEVEL);
+        let attack_speed = base.attack_speed.base * (1.0 + base.attack_speed.per_level / 100.0 * (level - 1) as f64);
+
+        Some(GodStats {
+            level,
+            health: base.health.at_level(level),
+            mana: base.mana.at_level(level),
+            physical_protection: base.physical_protection.at_level(level),
+            magical_protection: base.magical_protection.at_level(level),
+            hp5: base.hp5.at_level(level),
+            mp5: base.mp5.at_level(level),
+            basic_attack_damage: base.basic_attack_damage.at_level(level),
+            attack_speed: attack_speed.min(ATTACK_SPEED_CAP),
+            movement_speed: base.movement_speed,
+        })
+    }
+}
+
+// Gods from gods.json, empty if it didn't load
+pub static GODS: Lazy<&BTreeMap<String, God>> = Lazy::new(|| &DATA.gods);
+
+/// Made-up stats for tests; gods.json doesn't carry real ones yet
+#[cfg(test)]
+pub fn test_stats() -> BaseStats {
+    serde_json::from_str(r#"{
+        "health": {"base": 400, "per_level": 80},
+        "mana": {"base": 250},
+        "physical_protection": {"base": 10, "per_level": 3},
+        "magical_protection": {"base": 30},
+        "hp5": {"base": 7},
+        "mp5": {"base": 5},
+        "basic_attack_damage": {"base": 34, "per_level": 1.5},
+        "attack_speed": {"base": 1.0, "per_level": 1.0},
+        "movement_speed": 360
+    }"#).unwrap()
+}
+
+/// A bundled god with `test_stats`
+#[cfg(test)]
+pub fn test_god(id: &str) -> God {
+    God { stats: Some(test_stats()), ..GODS[id].clone() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_scale_with_level() {
+        let god = test_god("agni");
+        let level1 = god.stats_at_level(1).unwrap();
+        let level20 = god.stats_at_level(20).unwrap();
+
+        assert_eq!(level1.health, 400.0);
+        assert_eq!(level20.health, 400.0 + 80.0 * 19.0);
+        assert_eq!(god.stats_at_level(2).unwrap().health, 480.0);
+        assert_eq!(level20.mana, 250.0);
+        assert!((god.stats_at_level(11).unwrap().attack_speed - 1.1).abs() < 1e-9);
+        assert_eq!(god.stats_at_level(0), Some(level1));
+        assert_eq!(god.stats_at_level(30), Some(level20));
+    }
+
+    #[test]
+    fn test_gods_without_stats_have_none() {
+        let json = r#"{"display_name": "Test", "class": "Mage", "abilities": []}"#;
+        let god: God = serde_json::from_str(json).unwrap();
+        assert_eq!(god.stats_at_level(20), None);
+    }
+
+    #[test]
+    fn test_build_applies_attack_speed_cap() {
+        let god = test_god("agni");
+        let build = BuildStats::from_items(["the_crusher"; 6]);
+        let stats = god.stats_at_level(20).unwrap().with_build(&build);
+
+        assert_eq!(stats.attack_speed, ATTACK_SPEED_CAP);
+        assert_eq!(stats.health, god.stats_at_level(20).unwrap().health);
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use crate::data::aliases::same_role;
+use crate::data::guides::{Guide, GUIDES};
+use crate::data::saved_guides::SavedGuide;
+
+/// Where a guide shown next to a god came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuideSource {
+    Curated, // shipped in guides.json
+    Mine,    // saved from the creator into localStorage
+}
+
+impl GuideSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            GuideSource::Curated => "Curated",
+            GuideSource::Mine => "Mine",
+        }
+    }
+
+    /// CSS modifier for the source badge
+    pub fn class(self) -> &'static str {
+        match self {
+            GuideSource::Curated => "curated",
+            GuideSource::Mine => "mine",
+        }
+    }
+}
+
+/// A curated or locally saved guide, with an ID that's stable across renders
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuideEntry {
+    pub id: String, // "curated_{god}_{role}" or the saved guide's ID
+    pub source: GuideSource,
+    pub guide: Guide,
+}
+
+impl GuideEntry {
+    /// Short name for switchers: the title of a saved guide, otherwise the source
+    pub fn label(&self) -> String {
+        match (&self.source, &self.guide.title) {
+            (GuideSource::Mine, Some(title)) if !title.trim().is_empty() => title.clone(),
+            _ => self.source.label().to_string(),
+        }
+    }
+}
+
+/// ID of a god's curated guide for a role; guides.json has at most one per god and role
+pub fn curated_id(god_id: &str, role: &str) -> String {
+    format!("curated_{}_{}", god_id, role.to_lowercase())
+}
+
+/// Group curated and saved guides by god, curated first
+pub fn merge_guides(curated: &HashMap<String, Vec<Guide>>, saved: &[SavedGuide]) -> HashMap<String, Vec<GuideEntry>> {
+    let mut merged: HashMap<String, Vec<GuideEntry>> = HashMap::new();
+
+    for (god_id, guides) in curated {
+        let entries = guides.iter().map(|guide| GuideEntry {
+            id: curated_id(god_id, &guide.role),
+            source: GuideSource::Curated,
+            guide: guide.clone(),
+        });
+        merged.entry(god_id.clone()).or_default().extend(entries);
+    }
+
+    for saved in saved {
+        if saved.guide.god_id.is_empty() {
+            continue;
+        }
+        merged.entry(saved.guide.god_id.clone()).or_default().push(GuideEntry {
+            id: saved.id.clone(),
+            source: GuideSource::Mine,
+            guide: saved.guide.clone(),
+        });
+    }
+
+    merged
+}
+
+/// Every guide the user can see, keyed by god
+pub fn all_guides(saved: &[SavedGuide]) -> HashMap<String, Vec<GuideEntry>> {
+    merge_guides(&GUIDES, saved)
+}
+
+/// Curated and saved guides for one god
+pub fn guides_for_god(god_id: &str, saved: &[SavedGuide]) -> Vec<GuideEntry> {
+    all_guides(saved).remove(god_id).unwrap_or_default()
+}
+
+/// Whether any of the entries is for `role` (aliases like "adc" count)
+pub fn has_role(entries: &[GuideEntry], role: &str) -> bool {
+    entries.iter().any(|entry| same_role(&entry.guide.role, role))
+}
+
+/// Distinct roles across entries, in first-seen order
+pub fn distinct_roles(entries: &[GuideEntry]) -> Vec<String> {
+    let mut roles: Vec<String> = Vec::new();
+    for entry in entries {
+        if !roles.iter().any(|role| same_role(role, &entry.guide.role)) {
+            roles.push(entry.guide.role.clone());
+        }
+    }
+    roles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_puts_curated_first() {
+        let mine = SavedGuide::new(Guide::new("agni", "Carry"), 1700000000000);
+        let other = SavedGuide::new(Guide::new("zeus", "Mid"), 1700000000001);
+        let merged = merge_guides(&GUIDES, &[mine.clone(), other]);
+
+        let agni = &merged["agni"];
+        assert_eq!(agni.len(), GUIDES["agni"].len() + 1);
+        assert!(agni[..GUIDES["agni"].len()].iter().all(|entry| entry.source == GuideSource::Curated));
+        assert_eq!(agni[0].id, curated_id("agni", &GUIDES["agni"][0].role));
+        assert!(agni[0].id.starts_with("curated_agni_"));
+        assert_eq!(agni.last().unwrap().id, mine.id);
+        assert_eq!(agni.last().unwrap().source, GuideSource::Mine);
+
+        // A god without curated guides still gets the saved one
+        assert_eq!(merged["zeus"].len(), 1);
+    }
+
+    #[test]
+    fn test_roles_match_across_sources() {
+        let merged = merge_guides(&GUIDES, &[
+            SavedGuide::new(Guide::new("agni", "Mid"), 1),
+            SavedGuide::new(Guide::new("agni", "Carry"), 2),
+        ]);
+        let agni = &merged["agni"];
+
+        assert!(has_role(agni, "adc"));
+        assert!(has_role(agni, "middle"));
+        assert!(!has_role(agni, "jungle"));
+        assert_eq!(distinct_roles(agni), vec!["mid", "Carry"]);
+    }
+
+    #[test]
+    fn test_entry_label() {
+        let mut saved = SavedGuide::new(Guide::new("agni", "mid"), 1);
+        assert_eq!(merge_guides(&HashMap::new(), &[saved.clone()])["agni"][0].label(), "Mine");
+
+        saved.guide.title = Some("Burn".to_string());
+        assert_eq!(merge_guides(&HashMap::new(), &[saved])["agni"][0].label(), "Burn");
+    }
+}
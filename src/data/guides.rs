@@ -1,6 +1,16 @@
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use once_cell::sync::Lazy;
+use crate::data::patches::current_patch;
+use crate::data::registry::DATA;
+
+/// Version of the serialized `Guide` format. Bump it whenever the shape changes
+/// and add the matching step to `data::migrations`.
+pub const GUIDE_SCHEMA_VERSION: u32 = 2;
+
+fn current_schema_version() -> u32 {
+    GUIDE_SCHEMA_VERSION
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimelineEntry {
@@ -19,8 +29,12 @@ impl PartialEq for TimelineEntry {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Guide {
+    #[serde(default = "current_schema_version")]
+    pub schema_version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     pub god_id: String,
     pub role: String,
     pub build: Vec<String>, // final build item IDs
@@ -30,11 +44,15 @@ pub struct Guide {
     pub skill_order: Vec<u8>, // indices of abilities to level up (0-3)
     #[serde(default)]
     pub strategy: Option<String>, // Markdown strategy guide
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patch: Option<String>, // game patch the guide was written on, `None` if unknown
 }
 
 impl Guide {
     pub fn new<T: Into<String>>(god_id: T, role: T) -> Self {
         Guide {
+            schema_version: GUIDE_SCHEMA_VERSION,
+            title: None,
             god_id: god_id.into(),
             role: role.into(),
             build: Vec::new(),
@@ -42,6 +60,7 @@ impl Guide {
             timeline: Vec::new(),
             skill_order: Vec::new(),
             strategy: None,
+            patch: Some(current_patch().id.to_string()),
         }
     }
 
@@ -71,8 +90,5 @@ impl Guide {
     }
 }
 
-// Load guides from JSON file
-pub static GUIDES: Lazy<HashMap<String, Vec<Guide>>> = Lazy::new(|| {
-    let guides_json = include_str!("json/guides.json");
-    serde_json::from_str(guides_json).expect("Failed to parse guides.json")
-});
\ No newline at end of file
+// Curated guides from guides.json, empty if it didn't load
+pub static GUIDES: Lazy<&HashMap<String, Vec<Guide>>> = Lazy::new(|| &DATA.guides);
\ No newline at end of file
@@ -0,0 +1,210 @@
+use std::fmt::Write;
+use crate::data::registry::{DataError, DataRegistry};
+
+// `resolve_item_alias`, but against the registry being checked
+fn resolve_item(data: &DataRegistry, name: &str) -> String {
+    data.aliases.items.iter()
+        .find(|(_, aliases)| aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name)))
+        .map(|(canonical, _)| canonical.clone())
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// IDs in one bundled file that another file doesn't define: god abilities,
+/// guide gods and items (after aliases), skill order gods and alias targets.
+/// Also flags a god with two curated guides for the same role.
+pub fn check_references(data: &DataRegistry) -> Vec<DataError> {
+    let mut errors = Vec::new();
+    let mut broken = |file: &'static str, path: String, reason: String| {
+        errors.push(DataError { file, path, reason });
+    };
+
+    for (god_id, god) in &data.gods {
+        for (i, ability) in god.abilities.iter().enumerate() {
+            if !data.abilities.contains_key(ability) {
+                broken("gods.json", format!("{}.abilities[{}]", god_id, i), format!("unknown ability `{}`", ability));
+            }
+        }
+    }
+
+    let mut guide_keys: Vec<&String> = data.guides.keys().collect();
+    guide_keys.sort();
+    for key in guide_keys {
+        let mut roles: Vec<String> = Vec::new();
+        for (i, guide) in data.guides[key].iter().enumerate() {
+            let path = format!("{}[{}]", key, i);
+            // Curated guide IDs are built from the god and role
+            let role = guide.role.to_lowercase();
+            if roles.contains(&role) {
+                broken("guides.json", format!("{}.role", path), format!("second `{}` guide for `{}`", guide.role, key));
+            }
+            roles.push(role);
+            if !data.gods.contains_key(&guide.god_id) {
+                broken("guides.json", format!("{}.god_id", path), format!("unknown god `{}`", guide.god_id));
+            } else if guide.god_id != *key {
+                broken("guides.json", format!("{}.god_id", path), format!("guide for `{}` filed under `{}`", guide.god_id, key));
+            }
+
+            let timeline = guide.timeline.iter().enumerate().flat_map(|(t, entry)| {
+                entry.items.iter().flatten().enumerate()
+                    .map(move |(n, item)| (format!("timeline[{}].items[{}]", t, n), item))
+            });
+            let items = guide.build.iter().enumerate().map(|(n, item)| (format!("build[{}]", n), item))
+                .chain(guide.relics.iter().enumerate().map(|(n, item)| (format!("relics[{}]", n), item)))
+                .chain(timeline);
+            for (field, item) in items {
+                if !data.items.contains_key(&resolve_item(data, item)) {
+                    broken("guides.json", format!("{}.{}", path, field), format!("unknown item `{}`", item));
+                }
+            }
+        }
+    }
+
+    let mut skill_gods: Vec<&String> = data.skill_order.keys().collect();
+    skill_gods.sort();
+    for god_id in skill_gods {
+        if !data.gods.contains_key(god_id) {
+            broken("skill_order.json", god_id.clone(), format!("unknown god `{}`", god_id));
+        }
+    }
+
+    let mut item_aliases: Vec<&String> = data.aliases.items.keys().collect();
+    item_aliases.sort();
+    for item in item_aliases {
+        if !data.items.contains_key(item) {
+            broken("aliases.json", format!("items.{}", item), format!("aliases for unknown item `{}`", item));
+        }
+    }
+    let mut ability_aliases: Vec<&String> = data.aliases.abilities.keys().collect();
+    ability_aliases.sort();
+    for ability in ability_aliases {
+        if !data.abilities.contains_key(ability) {
+            broken("aliases.json", format!("abilities.{}", ability), format!("aliases for unknown ability `{}`", ability));
+        }
+    }
+
+    errors
+}
+
+// Icons the data points at, as paths under `assets/` with the file and ID
+// that need them. Every item, god and ability is shown somewhere.
+#[cfg(test)]
+fn referenced_assets(data: &DataRegistry) -> Vec<(&'static str, String, String)> {
+    let mut assets: Vec<(&'static str, String, String)> = Vec::new();
+    let mut items: Vec<&String> = data.items.keys().collect();
+    items.sort();
+    assets.extend(items.into_iter().map(|id| ("items.json", id.clone(), format!("items/{}.png", id))));
+    assets.extend(data.gods.keys().map(|id| ("gods.json", id.clone(), format!("gods/{}.png", id))));
+    let mut abilities: Vec<&String> = data.abilities.keys().collect();
+    abilities.sort();
+    assets.extend(abilities.into_iter().map(|id| ("abilities.json", id.clone(), format!("abilities/{}.png", id))));
+    assets
+}
+
+/// Referenced icons `exists` can't find. Only tests can look at the assets
+/// folder; the wasm build has no filesystem.
+#[cfg(test)]
+pub fn check_assets(data: &DataRegistry, exists: impl Fn(&str) -> bool) -> Vec<DataError> {
+    referenced_assets(data).into_iter()
+        .filter(|(_, _, asset)| !exists(asset))
+        .map(|(file, path, asset)| DataError { file, path, reason: format!("missing assets/{}", asset) })
+        .collect()
+}
+
+/// One problem per line, for logs and test failures
+pub fn report(errors: &[DataError]) -> String {
+    let mut out = format!("{} broken reference{}", errors.len(), if errors.len() == 1 { "" } else { "s" });
+    for error in errors {
+        let _ = write!(out, "\n  {}", error);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use crate::data::registry::{Source, Sources, DATA};
+
+    // Consumables the icon scrape missed. Remove an entry once its icon lands.
+    const KNOWN_MISSING_ICONS: &[&str] = &[
+        "items/barons_brew.png",
+        "items/chalice_of_healing.png",
+        "items/healing_potion.png",
+        "items/lively_acorn.png",
+        "items/mana_potion.png",
+        "items/multi_potion.png",
+        "items/sentry_ward.png",
+    ];
+
+    fn asset_exists(asset: &str) -> bool {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("assets").join(asset).exists()
+    }
+
+    fn with(edit: impl FnOnce(&mut Sources)) -> DataRegistry {
+        let mut sources = Sources::bundled();
+        edit(&mut sources);
+        DataRegistry::load(&sources)
+    }
+
+    #[test]
+    fn test_bundled_references_resolve() {
+        let errors = check_references(&DATA);
+        assert!(errors.is_empty(), "{}", report(&errors));
+    }
+
+    #[test]
+    fn test_bundled_assets_exist() {
+        let errors: Vec<DataError> = check_assets(&DATA, asset_exists).into_iter()
+            .filter(|error| !KNOWN_MISSING_ICONS.iter().any(|asset| error.reason.ends_with(asset)))
+            .collect();
+        assert!(errors.is_empty(), "{}", report(&errors));
+
+        let found: Vec<&&str> = KNOWN_MISSING_ICONS.iter().filter(|asset| asset_exists(asset)).collect();
+        assert!(found.is_empty(), "no longer missing, remove from KNOWN_MISSING_ICONS: {:?}", found);
+    }
+
+    #[test]
+    fn test_finds_each_kind_of_broken_reference() {
+        let data = with(|sources| {
+            sources.gods = Source { file: "gods.json", json: r#"{"agni": {"display_name": "Agni", "class": "Mage", "abilities": ["combustion", "fireball"]}}"# };
+            sources.guides = Source { file: "guides.json", json: r#"{
+                "agni": [
+                    {"god_id": "agnii", "role": "mid", "build": ["tahuti", "nope"], "relics": [], "timeline": []},
+                    {"god_id": "agni", "role": "Mid", "build": [], "relics": [], "timeline": []}
+                ],
+                "zeus": [{"god_id": "agni", "role": "mid", "build": [], "relics": [], "timeline": [{"percent": 0, "items": ["magic_focus", "nope"]}]}]
+            }"# };
+            sources.skill_order = Source { file: "skill_order.json", json: r#"{"agni": [1, 2, 3], "nobody": [1]}"# };
+            sources.aliases = Source { file: "aliases.json", json: r#"{
+                "items": {"rod_of_tahuti": ["tahuti"], "old_rod": ["rod"]},
+                "abilities": {"flame_wave": ["fwave"], "old_wave": ["wave"]}
+            }"# };
+        });
+
+        let found: Vec<String> = check_references(&data).iter().map(ToString::to_string).collect();
+        assert_eq!(found, vec![
+            "gods.json at agni.abilities[1]: unknown ability `fireball`",
+            "guides.json at agni[0].god_id: unknown god `agnii`",
+            "guides.json at agni[0].build[1]: unknown item `nope`",
+            "guides.json at agni[1].role: second `Mid` guide for `agni`",
+            "guides.json at zeus[0].god_id: guide for `agni` filed under `zeus`",
+            "guides.json at zeus[0].timeline[0].items[1]: unknown item `nope`",
+            "skill_order.json at nobody: unknown god `nobody`",
+            "aliases.json at items.old_rod: aliases for unknown item `old_rod`",
+            "aliases.json at abilities.old_wave: aliases for unknown ability `old_wave`",
+        ]);
+        assert_eq!(data.reference_errors.len(), found.len());
+    }
+
+    #[test]
+    fn test_missing_assets_and_report() {
+        let errors = check_assets(&DATA, |asset| asset != "gods/agni.png");
+        assert_eq!(errors, vec![DataError {
+            file: "gods.json",
+            path: "agni".to_string(),
+            reason: "missing assets/gods/agni.png".to_string(),
+        }]);
+        assert_eq!(report(&errors), "1 broken reference\n  gods.json at agni: missing assets/gods/agni.png");
+        assert_eq!(report(&[]), "0 broken references");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::data::item_tree::total_cost;
+use crate::data::items::{Item, ItemStat, ItemTag};
+
+/// Sections of the item browser, in display order. An item goes in the first
+/// section whose tag it has.
+pub const ITEM_GROUPS: [ItemTag; 10] = [
+    ItemTag::Starter,
+    ItemTag::Tier1,
+    ItemTag::Tier2,
+    ItemTag::Tier3,
+    ItemTag::Tier4,
+    ItemTag::Evolved,
+    ItemTag::Glyph,
+    ItemTag::Relic,
+    ItemTag::Consumable,
+    ItemTag::Shard,
+];
+
+pub fn group_label(group: Option<&ItemTag>) -> &'static str {
+    match group {
+        Some(ItemTag::Starter) => "Starter",
+        Some(ItemTag::Tier1) => "Tier 1",
+        Some(ItemTag::Tier2) => "Tier 2",
+        Some(ItemTag::Tier3) => "Tier 3",
+        Some(ItemTag::Tier4) => "Tier 4",
+        Some(ItemTag::Evolved) => "Evolved",
+        Some(ItemTag::Glyph) => "Glyphs",
+        Some(ItemTag::Relic) => "Relics",
+        Some(ItemTag::Consumable) => "Consumables",
+        Some(ItemTag::Shard) => "Shards",
+        None => "Other",
+    }
+}
+
+/// The browser section an item is listed under, `None` for untagged items
+pub fn item_group(item: &Item) -> Option<ItemTag> {
+    ITEM_GROUPS.iter().find(|group| item.tags.contains(group)).cloned()
+}
+
+/// Column the item table is sorted by
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortKey {
+    Name,
+    Price,
+    Stat(ItemStat),
+}
+
+impl SortKey {
+    /// Numbers read best biggest first, names A to Z
+    pub fn descending_by_default(&self) -> bool {
+        !matches!(self, SortKey::Name)
+    }
+}
+
+/// Sort items by a column, breaking ties by name
+pub fn sort_items(items: &mut [(&String, &Item)], key: &SortKey, descending: bool) {
+    items.sort_by(|(a_id, a), (b_id, b)| {
+        let order = match key {
+            SortKey::Name => a.display_name.cmp(&b.display_name),
+            SortKey::Price => total_cost(a_id).cmp(&total_cost(b_id)),
+            SortKey::Stat(stat) => {
+                let value = |item: &Item| item.stats.get(stat).copied().unwrap_or_default();
+                value(a).cmp(&value(b))
+            }
+        };
+        let order = if descending { order.reverse() } else { order };
+        order.then_with(|| a.display_name.cmp(&b.display_name))
+    });
+}
+
+/// Stats at least one of the items has, in `ItemStat` order
+pub fn stat_columns<'a>(items: impl IntoIterator<Item = &'a Item>) -> Vec<ItemStat> {
+    let mut stats: Vec<ItemStat> = items.into_iter()
+        .flat_map(|item| item.stats.keys().cloned())
+        .collect();
+    stats.sort();
+    stats.dedup();
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::items::ITEMS;
+
+    #[test]
+    fn test_every_item_has_one_group() {
+        let grouped = ITEMS.values().filter(|item| item_group(item).is_some()).count();
+        assert_eq!(grouped, ITEMS.len());
+        assert_eq!(item_group(&ITEMS["rod_of_tahuti"]), Some(ItemTag::Tier3));
+    }
+
+    #[test]
+    fn test_sort_by_stat() {
+        let mut items: Vec<(&String, &Item)> = ITEMS.iter().collect();
+        sort_items(&mut items, &SortKey::Stat(ItemStat::MagicalPower), true);
+        let powers: Vec<i32> = items.iter()
+            .map(|(_, item)| item.stats.get(&ItemStat::MagicalPower).copied().unwrap_or_default())
+            .collect();
+        assert!(powers.windows(2).all(|pair| pair[0] >= pair[1]));
+
+        sort_items(&mut items, &SortKey::Name, false);
+        assert!(items.windows(2).all(|pair| pair[0].1.display_name <= pair[1].1.display_name));
+    }
+
+    #[test]
+    fn test_stat_columns() {
+        let columns = stat_columns([&ITEMS["rod_of_tahuti"], &ITEMS["obsidian_shard"]]);
+        assert!(columns.contains(&ItemStat::MagicalPower));
+        assert!(columns.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(stat_columns([]).is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+use crate::data::item_tree::total_cost;
+use crate::data::items::{Effect, Item, ItemStat, ItemTag};
+use crate::data::search::{fuzzy_matches, normalize};
+use crate::utils::format::format_stat_name;
+
+// Short names players use for stats
+const STAT_ALIASES: &[(&str, ItemStat)] = &[
+    ("pp", ItemStat::PhysicalPower),
+    ("physpower", ItemStat::PhysicalPower),
+    ("magpower", ItemStat::MagicalPower),
+    ("magicpower", ItemStat::MagicalPower),
+    ("hp", ItemStat::Health),
+    ("physprot", ItemStat::PhysicalProtection),
+    ("physprots", ItemStat::PhysicalProtection),
+    ("magprot", ItemStat::MagicalProtection),
+    ("magprots", ItemStat::MagicalProtection),
+    ("as", ItemStat::AttackSpeed),
+    ("physpen", ItemStat::PhysicalPenetration),
+    ("magpen", ItemStat::MagicalPenetration),
+    ("magicpen", ItemStat::MagicalPenetration),
+    ("crit", ItemStat::CriticalStrikeChance),
+    ("cdr", ItemStat::CooldownReduction),
+    ("ms", ItemStat::MovementSpeed),
+    ("dr", ItemStat::DamageReduction),
+];
+
+// Keywords whose effect text is worded several ways
+const KEYWORD_PHRASES: &[(&str, &[&str])] = &[
+    ("anti heal", &[
+        "healing taken reduced",
+        "healing received by enemy gods is reduced",
+        "have their healing reduced",
+        "reduced healing",
+        "reduces their healing",
+    ]),
+];
+
+/// Item picker query, e.g. `has:magpen effect:active price:<2600 "anti-heal"`.
+///
+/// `has:`/`stat:` take a stat, `effect:` a Passive/Active/Glyph/Aura effect, `tag:` an
+/// item tag (`tier:3` works too), `price:` a range (`<2600`, `>=2000`, `2000-2800`) and
+/// `text:` or a quoted phrase a keyword from the effect text. Anything else matches the
+/// item's name or effect text.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ItemFilter {
+    pub stats: Vec<ItemStat>,
+    pub effects: Vec<Effect>,
+    pub tags: Vec<ItemTag>,
+    pub min_price: Option<u32>,
+    pub max_price: Option<u32>,
+    pub keywords: Vec<String>, // normalized effect text keywords
+    pub words: Vec<String>,    // free text
+    pub unknown: Vec<String>,  // filters that couldn't be read
+}
+
+// Split on whitespace, keeping "quoted phrases" together. Quoted tokens are flagged.
+fn tokenize(query: &str) -> Vec<(String, bool)> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in query.chars() {
+        match c {
+            '"' => {
+                if quoted || !current.is_empty() {
+                    tokens.push((std::mem::take(&mut current), quoted));
+                }
+                quoted = !quoted;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    tokens.push((std::mem::take(&mut current), false));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push((current, quoted));
+    }
+    tokens.retain(|(token, _)| !token.trim().is_empty());
+    tokens
+}
+
+fn compact(text: &str) -> String {
+    normalize(text).replace(' ', "")
+}
+
+/// Read a stat by enum name ("MagicalPenetration"), display name or short alias ("magpen")
+pub fn parse_stat(text: &str) -> Option<ItemStat> {
+    let wanted = compact(text);
+    ItemStat::ALL.iter().find(|stat| compact(&format!("{:?}", stat)) == wanted)
+        .or_else(|| STAT_ALIASES.iter().find(|(alias, _)| *alias == wanted).map(|(_, stat)| stat))
+        .or_else(|| ItemStat::ALL.iter().find(|stat| compact(format_stat_name(stat)) == wanted))
+        .cloned()
+}
+
+fn parse_effect(text: &str) -> Option<Effect> {
+    let wanted = compact(text);
+    Effect::ALL.iter().find(|effect| compact(&format!("{:?}", effect)) == wanted).cloned()
+}
+
+fn parse_tag(text: &str) -> Option<ItemTag> {
+    let wanted = compact(text);
+    let wanted = match wanted.strip_prefix('t') {
+        Some(tier) if tier.parse::<u8>().is_ok() => format!("tier{}", tier),
+        _ => wanted,
+    };
+    ItemTag::ALL.iter().find(|tag| compact(&format!("{:?}", tag)) == wanted).cloned()
+}
+
+// `<2600`, `<=2600`, `>2000`, `>=2000`, `2000-2800` or an exact price
+fn parse_price(text: &str) -> Option<(Option<u32>, Option<u32>)> {
+    let text = text.trim();
+    if let Some(max) = text.strip_prefix("<=") {
+        return Some((None, Some(max.parse().ok()?)));
+    }
+    if let Some(max) = text.strip_prefix('<') {
+        return Some((None, Some(max.parse::<u32>().ok()?.checked_sub(1)?)));
+    }
+    if let Some(min) = text.strip_prefix(">=") {
+        return Some((Some(min.parse().ok()?), None));
+    }
+    if let Some(min) = text.strip_prefix('>') {
+        return Some((Some(min.parse::<u32>().ok()?.checked_add(1)?), None));
+    }
+    if let Some((min, max)) = text.split_once('-') {
+        return Some((Some(min.parse().ok()?), Some(max.parse().ok()?)));
+    }
+    let exact = text.parse().ok()?;
+    Some((Some(exact), Some(exact)))
+}
+
+// Whether normalized text contains the keyword at the start of a word
+fn mentions(text: &str, keyword: &str) -> bool {
+    let phrases: Vec<&str> = KEYWORD_PHRASES.iter()
+        .find(|(name, _)| compact(name) == keyword.replace(' ', ""))
+        .map(|(_, phrases)| phrases.to_vec())
+        .unwrap_or_else(|| vec![keyword]);
+    let text = format!(" {}", text);
+    phrases.iter().any(|phrase| text.contains(&format!(" {}", phrase)))
+}
+
+impl ItemFilter {
+    pub fn parse(query: &str) -> ItemFilter {
+        let mut filter = ItemFilter::default();
+
+        for (token, quoted) in tokenize(query) {
+            if quoted {
+                filter.keywords.push(normalize(&token));
+                continue;
+            }
+            let Some((key, value)) = token.split_once(':') else {
+                filter.words.push(token);
+                continue;
+            };
+
+            let parsed = match key.to_lowercase().as_str() {
+                "has" => parse_stat(value).map(|stat| filter.stats.push(stat))
+                    .or_else(|| parse_effect(value).map(|effect| filter.effects.push(effect))),
+                "stat" => parse_stat(value).map(|stat| filter.stats.push(stat)),
+                "effect" => parse_effect(value).map(|effect| filter.effects.push(effect)),
+                "tag" => parse_tag(value).map(|tag| filter.tags.push(tag)),
+                "tier" => parse_tag(&format!("tier{}", value)).map(|tag| filter.tags.push(tag)),
+                "price" => parse_price(value).map(|(min, max)| {
+                    filter.min_price = min.or(filter.min_price);
+                    filter.max_price = max.or(filter.max_price);
+                }),
+                "text" if !value.is_empty() => {
+                    filter.keywords.push(normalize(value));
+                    Some(())
+                }
+                _ => None,
+            };
+            if parsed.is_none() {
+                filter.unknown.push(token);
+            }
+        }
+
+        filter
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == ItemFilter::default()
+    }
+
+    pub fn matches(&self, item_id: &str, item: &Item) -> bool {
+        let effect_text = normalize(&item.effects.values().cloned().collect::<Vec<_>>().join(" "));
+
+        let price = total_cost(item_id);
+        let price_ok = self.min_price.is_none_or(|min| price >= min)
+            && self.max_price.is_none_or(|max| price <= max);
+
+        let words_ok = self.words.is_empty() || {
+            let phrase = self.words.join(" ");
+            fuzzy_matches(&phrase, &[item_id, &item.display_name])
+                || self.words.iter().all(|word| mentions(&effect_text, &normalize(word)))
+        };
+
+        self.unknown.is_empty()
+            && price_ok
+            && words_ok
+            && self.stats.iter().all(|stat| item.stats.contains_key(stat))
+            && self.effects.iter().all(|effect| item.effects.contains_key(effect))
+            && self.tags.iter().all(|tag| item.tags.contains(tag))
+            && self.keywords.iter().all(|keyword| mentions(&effect_text, keyword))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::items::ITEMS;
+
+    fn matching(query: &str) -> Vec<&'static str> {
+        let filter = ItemFilter::parse(query);
+        let mut ids: Vec<&str> = ITEMS.iter()
+            .filter(|(id, item)| filter.matches(id, item))
+            .map(|(id, _)| id.as_str())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_parse() {
+        let filter = ItemFilter::parse(r#"has:magpen stat:Cooldown_Reduction effect:active tier:3 price:<2600 "anti-heal" rod"#);
+        assert_eq!(filter.stats, vec![ItemStat::MagicalPenetration, ItemStat::CooldownReduction]);
+        assert_eq!(filter.effects, vec![Effect::Active]);
+        assert_eq!(filter.tags, vec![ItemTag::Tier3]);
+        assert_eq!((filter.min_price, filter.max_price), (None, Some(2599)));
+        assert_eq!(filter.keywords, vec!["anti heal"]);
+        assert_eq!(filter.words, vec!["rod"]);
+        assert!(filter.unknown.is_empty());
+
+        assert_eq!(ItemFilter::parse("has:nothing").unknown, vec!["has:nothing"]);
+        assert_eq!(ItemFilter::parse("price:2000-2800").min_price, Some(2000));
+        assert_eq!(ItemFilter::parse("price:>4294967295").unknown, vec!["price:>4294967295"]);
+        assert_eq!(ItemFilter::parse("price:<0").unknown, vec!["price:<0"]);
+        assert!(ItemFilter::parse("  ").is_empty());
+    }
+
+    #[test]
+    fn test_stat_names() {
+        assert_eq!(parse_stat("MagicalPenetration"), Some(ItemStat::MagicalPenetration));
+        assert_eq!(parse_stat("magical penetration"), Some(ItemStat::MagicalPenetration));
+        assert_eq!(parse_stat("cdr"), Some(ItemStat::CooldownReduction));
+        assert_eq!(parse_tag("t2"), Some(ItemTag::Tier2));
+    }
+
+    #[test]
+    fn test_matches() {
+        let pen_cdr = matching("has:MagicalPenetration has:cdr");
+        assert!(!pen_cdr.is_empty());
+        for id in &pen_cdr {
+            let item = &ITEMS[*id];
+            assert!(item.stats.contains_key(&ItemStat::MagicalPenetration));
+            assert!(item.stats.contains_key(&ItemStat::CooldownReduction));
+        }
+
+        assert!(matching("\"anti-heal\"").contains(&"tainted_steel"));
+        assert!(matching("antiheal").contains(&"divine_ruin"));
+        assert!(matching("tahuti").contains(&"rod_of_tahuti"));
+        assert!(matching("has:nothing").is_empty());
+
+        for id in matching("price:2000-2500 effect:passive") {
+            let item = &ITEMS[id];
+            assert!((2000..=2500).contains(&total_cost(id)));
+            assert!(item.effects.contains_key(&Effect::Passive));
+        }
+    }
+}
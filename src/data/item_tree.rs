@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+use once_cell::sync::Lazy;
+use crate::data::items::{Item, ITEMS};
+
+/// Items each item upgrades into, the reverse of `Item::builds_from`, cheapest first
+pub static BUILDS_INTO: Lazy<HashMap<String, Vec<String>>> = Lazy::new(|| {
+    let mut into: HashMap<String, Vec<String>> = HashMap::new();
+    for (id, item) in ITEMS.iter() {
+        for component in &item.builds_from {
+            into.entry(component.clone()).or_default().push(id.clone());
+        }
+    }
+    for upgrades in into.values_mut() {
+        upgrades.sort_by_key(|id| (total_cost(id), id.clone()));
+    }
+    into
+});
+
+pub fn builds_from(item_id: &str) -> &'static [String] {
+    ITEMS.get(item_id).map(|item| item.builds_from.as_slice()).unwrap_or_default()
+}
+
+pub fn builds_into(item_id: &str) -> &'static [String] {
+    BUILDS_INTO.get(item_id).map(Vec::as_slice).unwrap_or_default()
+}
+
+/// Gold to buy the item from nothing. Items priced 0 that upgrade something
+/// (evolved items) cost what their components do.
+pub fn total_cost(item_id: &str) -> u32 {
+    cost_in(&ITEMS, item_id, &mut HashSet::new())
+}
+
+// `path` holds the items being costed further up, so a recipe that loops
+// back on itself counts the repeat as free instead of recursing forever
+fn cost_in<'a>(items: &'a HashMap<String, Item>, item_id: &'a str, path: &mut HashSet<&'a str>) -> u32 {
+    let Some(item) = items.get(item_id) else {
+        return 0;
+    };
+    if item.price > 0 || !path.insert(item_id) {
+        return item.price;
+    }
+    let cost = item.builds_from.iter().map(|component| cost_in(items, component, path)).sum();
+    path.remove(item_id);
+    cost
+}
+
+/// Gold on top of the components
+pub fn upgrade_cost(item_id: &str) -> u32 {
+    let components: u32 = builds_from(item_id).iter().map(|component| total_cost(component)).sum();
+    total_cost(item_id).saturating_sub(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tree_is_consistent() {
+        for (id, item) in ITEMS.iter() {
+            for component in &item.builds_from {
+                assert!(ITEMS.contains_key(component), "{} builds from unknown {}", id, component);
+                assert!(builds_into(component).contains(id));
+            }
+            assert!(total_cost(id) >= builds_from(id).iter().map(|c| total_cost(c)).sum::<u32>(), "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_costs() {
+        assert_eq!(builds_from("calamitous_rod_of_tahuti"), ["rod_of_tahuti"]);
+        assert!(builds_into("rod_of_tahuti").contains(&"perfected_rod_of_tahuti".to_string()));
+        assert_eq!(upgrade_cost("calamitous_rod_of_tahuti"), total_cost("calamitous_rod_of_tahuti") - total_cost("rod_of_tahuti"));
+
+        // Evolved items are priced 0 but cost their base item
+        assert_eq!(total_cost("evolved_book_of_thoth"), total_cost("book_of_thoth"));
+        assert_eq!(upgrade_cost("evolved_book_of_thoth"), 0);
+        assert_eq!(total_cost("no_such_item"), 0);
+    }
+
+    #[test]
+    fn test_cyclic_recipes_terminate() {
+        let items: HashMap<String, Item> = serde_json::from_str(r#"{
+            "a": {"display_name": "A", "price": 0, "builds_from": ["b"]},
+            "b": {"display_name": "B", "price": 0, "builds_from": ["a", "c", "c"]},
+            "c": {"display_name": "C", "price": 300}
+        }"#).unwrap();
+        assert_eq!(cost_in(&items, "a", &mut HashSet::new()), 600);
+        assert_eq!(cost_in(&items, "b", &mut HashSet::new()), 600);
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::{HashMap, BTreeMap};
 use serde::{Deserialize, Serialize};
 use once_cell::sync::Lazy;
+use crate::data::registry::DATA;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ItemStat {
@@ -26,6 +27,31 @@ pub enum ItemStat {
     DamageReduction,
 }
 
+impl ItemStat {
+    pub const ALL: [ItemStat; 20] = [
+        ItemStat::BasicAttackDamage,
+        ItemStat::PhysicalPower,
+        ItemStat::MagicalPower,
+        ItemStat::Health,
+        ItemStat::PhysicalProtection,
+        ItemStat::MagicalProtection,
+        ItemStat::Mana,
+        ItemStat::HP5,
+        ItemStat::MP5,
+        ItemStat::AttackSpeed,
+        ItemStat::PhysicalLifesteal,
+        ItemStat::MagicalLifesteal,
+        ItemStat::PhysicalPenetration,
+        ItemStat::MagicalPenetration,
+        ItemStat::PhysicalPenetrationPercent,
+        ItemStat::MagicalPenetrationPercent,
+        ItemStat::CriticalStrikeChance,
+        ItemStat::CooldownReduction,
+        ItemStat::MovementSpeed,
+        ItemStat::DamageReduction,
+    ];
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Effect {
     Passive,
@@ -34,6 +60,10 @@ pub enum Effect {
     Aura,
 }
 
+impl Effect {
+    pub const ALL: [Effect; 4] = [Effect::Passive, Effect::Active, Effect::Glyph, Effect::Aura];
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ItemTag {
     Tier1,
@@ -48,6 +78,21 @@ pub enum ItemTag {
     Shard,
 }
 
+impl ItemTag {
+    pub const ALL: [ItemTag; 10] = [
+        ItemTag::Tier1,
+        ItemTag::Tier2,
+        ItemTag::Tier3,
+        ItemTag::Tier4,
+        ItemTag::Consumable,
+        ItemTag::Evolved,
+        ItemTag::Glyph,
+        ItemTag::Starter,
+        ItemTag::Relic,
+        ItemTag::Shard,
+    ];
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Item {
     pub display_name: String,
@@ -58,10 +103,11 @@ pub struct Item {
     pub effects: BTreeMap<Effect, String>,
     #[serde(default)]
     pub tags: Vec<ItemTag>,
+    // Item IDs this one upgrades. Only glyphs, evolved items and starter
+    // upgrades have recipes so far; tier 1-3 components aren't recorded yet.
+    #[serde(default)]
+    pub builds_from: Vec<String>,
 }
 
-// Load items from JSON file
-pub static ITEMS: Lazy<HashMap<String, Item>> = Lazy::new(|| {
-    let items_json = include_str!("json/items.json");
-    serde_json::from_str(items_json).expect("Failed to parse items.json")
-});
\ No newline at end of file
+// Items from items.json, empty if it didn't load
+pub static ITEMS: Lazy<&HashMap<String, Item>> = Lazy::new(|| &DATA.items);
\ No newline at end of file
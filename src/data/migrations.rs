@@ -0,0 +1,194 @@
+use serde_json::{Map, Value};
+use crate::data::guides::{Guide, GUIDE_SCHEMA_VERSION};
+
+/// Schema version of a serialized guide.
+/// Guides written before versioning are v1 if they use the old creator
+/// shape (`god`, `progress`), otherwise they already match v2.
+pub fn schema_version(value: &Value) -> u32 {
+    if let Some(version) = value.get("schema_version").and_then(Value::as_u64) {
+        return version as u32;
+    }
+    if value.get("god_id").is_none() && value.get("god").is_some() {
+        1
+    } else {
+        2
+    }
+}
+
+/// Bring a serialized guide up to `GUIDE_SCHEMA_VERSION`, one step at a time.
+/// Fields that aren't part of the guide (like a saved guide's `id`) pass through untouched.
+pub fn migrate_guide_value(mut value: Value) -> Result<Value, String> {
+    let mut version = schema_version(&value);
+    if version > GUIDE_SCHEMA_VERSION {
+        return Err(format!(
+            "guide uses schema version {} but this version of Grappul only reads up to {}",
+            version, GUIDE_SCHEMA_VERSION
+        ));
+    }
+
+    while version < GUIDE_SCHEMA_VERSION {
+        value = match version {
+            1 => v1_to_v2(value)?,
+            _ => return Err(format!("no migration from schema version {}", version)),
+        };
+        version += 1;
+    }
+
+    if let Some(object) = value.as_object_mut() {
+        object.insert("schema_version".to_string(), Value::from(GUIDE_SCHEMA_VERSION));
+    }
+    Ok(value)
+}
+
+/// Parse a guide in any known schema version
+pub fn parse_guide(json: &str) -> Result<Guide, String> {
+    let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let value = migrate_guide_value(value)?;
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+// v2 has no timeline label. The old creator labelled entries "{progress}%"
+// itself, so only a label the author changed (like "5 mins") goes into the tip.
+fn timeline_tip(time: Option<Value>, description: Option<Value>, progress: f64) -> Value {
+    let text = |value: Option<Value>| match value {
+        Some(Value::String(text)) if !text.trim().is_empty() => Some(text.trim().to_string()),
+        _ => None,
+    };
+    let label = text(time).filter(|label| {
+        *label != format!("{}%", progress as u32) && *label != format!("{}%", progress.round() as u32)
+    });
+
+    match (label, text(description)) {
+        (Some(label), Some(description)) => Value::String(format!("{}: {}", label, description)),
+        (Some(text), None) | (None, Some(text)) => Value::String(text),
+        (None, None) => Value::Null,
+    }
+}
+
+/// v1 is the creator's old `SavedBuild`: `god` instead of `god_id`, a timeline of
+/// `{ time, items, description, progress }` and an always-present `strategy` string
+fn v1_to_v2(value: Value) -> Result<Value, String> {
+    let Value::Object(mut old) = value else {
+        return Err("expected a JSON object".to_string());
+    };
+
+    let non_empty_string = |value: Option<Value>| match value {
+        Some(Value::String(text)) if !text.trim().is_empty() => Value::String(text),
+        _ => Value::Null,
+    };
+
+    let timeline = match old.remove("timeline") {
+        Some(Value::Array(entries)) => entries.into_iter().map(|entry| {
+            let mut entry = match entry {
+                Value::Object(entry) => entry,
+                _ => Map::new(),
+            };
+            let progress = entry.get("progress")
+                .and_then(Value::as_f64)
+                .unwrap_or(0.0)
+                .clamp(0.0, 100.0);
+            let percent = progress.round() as u8;
+            let items = match entry.remove("items") {
+                Some(Value::Array(items)) if !items.is_empty() => Value::Array(items),
+                _ => Value::Null,
+            };
+
+            let mut new = Map::new();
+            new.insert("percent".to_string(), Value::from(percent));
+            new.insert("items".to_string(), items);
+            new.insert("tip".to_string(), timeline_tip(entry.remove("time"), entry.remove("description"), progress));
+            Value::Object(new)
+        }).collect(),
+        _ => Vec::new(),
+    };
+
+    let mut new = Map::new();
+    for key in ["id", "created_at", "role", "build", "relics", "skill_order"] {
+        if let Some(value) = old.remove(key) {
+            new.insert(key.to_string(), value);
+        }
+    }
+    new.insert("god_id".to_string(), old.remove("god").unwrap_or(Value::String(String::new())));
+    new.insert("title".to_string(), non_empty_string(old.remove("title")));
+    new.insert("timeline".to_string(), Value::Array(timeline));
+    new.insert("strategy".to_string(), non_empty_string(old.remove("strategy")));
+    new.insert("schema_version".to_string(), Value::from(2));
+
+    Ok(Value::Object(new))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::guides::GUIDES;
+
+    const V1_SAVED_BUILD: &str = r#"{
+        "id": "build_1700000000000",
+        "title": "Burn it all",
+        "god": "agni",
+        "role": "Mid",
+        "build": ["spear_of_the_magus", "rod_of_tahuti"],
+        "relics": ["purification_beads"],
+        "timeline": [
+            {"time": "0%", "items": ["magic_focus"], "description": "Start", "progress": 0.0},
+            {"time": "42%", "items": [], "description": "", "progress": 42.6}
+        ],
+        "skill_order": [3, 2, 2, 1, 4],
+        "strategy": "",
+        "created_at": "1700000000000"
+    }"#;
+
+    #[test]
+    fn test_migrates_v1_saved_build() {
+        let value: Value = serde_json::from_str(V1_SAVED_BUILD).unwrap();
+        assert_eq!(schema_version(&value), 1);
+
+        let migrated = migrate_guide_value(value).unwrap();
+        assert_eq!(migrated["id"], "build_1700000000000");
+        assert_eq!(migrated["schema_version"], GUIDE_SCHEMA_VERSION);
+
+        let guide: Guide = serde_json::from_value(migrated).unwrap();
+        assert_eq!(guide.god_id, "agni");
+        assert_eq!(guide.title.as_deref(), Some("Burn it all"));
+        assert_eq!(guide.timeline[0].percent, 0);
+        assert_eq!(guide.timeline[0].items, Some(vec!["magic_focus".to_string()]));
+        assert_eq!(guide.timeline[0].tip.as_deref(), Some("Start"));
+        assert_eq!(guide.timeline[1].percent, 43);
+        assert_eq!(guide.timeline[1].items, None);
+        assert_eq!(guide.timeline[1].tip, None);
+        assert_eq!(guide.skill_order, vec![3, 2, 2, 1, 4]);
+        assert_eq!(guide.strategy, None);
+    }
+
+    #[test]
+    fn test_keeps_edited_timeline_labels() {
+        let json = r#"{
+            "god": "agni",
+            "role": "Mid",
+            "build": [],
+            "relics": [],
+            "timeline": [
+                {"time": "5 mins", "items": [], "description": "", "progress": 20.0},
+                {"time": "Mid game", "items": [], "description": "Buy boots", "progress": 50.0},
+                {"time": "75%", "items": [], "description": "Sell starter", "progress": 75.4}
+            ]
+        }"#;
+        let guide = parse_guide(json).unwrap();
+        let tips: Vec<Option<&str>> = guide.timeline.iter().map(|entry| entry.tip.as_deref()).collect();
+        assert_eq!(tips, vec![Some("5 mins"), Some("Mid game: Buy boots"), Some("Sell starter")]);
+    }
+
+    #[test]
+    fn test_current_guides_round_trip() {
+        for guide in GUIDES.values().flatten() {
+            let json = serde_json::to_string(guide).unwrap();
+            assert_eq!(&parse_guide(&json).unwrap(), guide);
+        }
+    }
+
+    #[test]
+    fn test_rejects_future_versions() {
+        let json = r#"{"schema_version": 99, "god_id": "agni", "role": "mid", "build": [], "relics": [], "timeline": []}"#;
+        assert!(parse_guide(json).is_err());
+    }
+}
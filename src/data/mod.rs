@@ -3,4 +3,23 @@ pub mod gods;
 pub mod abilities;
 pub mod guides;
 pub mod aliases;
-pub mod icon_paths;
\ No newline at end of file
+pub mod icon_paths;
+pub mod build_stats;
+pub mod formulas;
+pub mod ability_calc;
+pub mod simulation;
+pub mod validation;
+pub mod migrations;
+pub mod saved_guides;
+pub mod guide_sources;
+pub mod share;
+pub mod compare;
+pub mod search;
+pub mod item_filter;
+pub mod item_browser;
+pub mod item_tree;
+pub mod patches;
+pub mod patch_diff;
+pub mod staleness;
+pub mod registry;
+pub mod integrity;
\ No newline at end of file
@@ -0,0 +1,308 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use crate::data::abilities::Ability;
+use crate::data::items::{Effect, Item, ItemStat};
+use crate::data::patches::{current_patch, PatchData, PATCHES};
+use crate::utils::format::format_stat_label;
+
+/// A value on both sides of a patch, `None` where it doesn't exist
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change<K, V> {
+    pub key: K,
+    pub before: Option<V>,
+    pub after: Option<V>,
+}
+
+/// What changed about an item that exists on both patches
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemChange {
+    pub id: String,
+    pub name: Option<(String, String)>,
+    pub price: Option<(u32, u32)>,
+    pub stats: Vec<Change<ItemStat, i32>>,      // in `ItemStat` order
+    pub effects: Vec<Change<Effect, String>>,   // in `Effect` order
+}
+
+/// What changed about an ability that exists on both patches
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilityChange {
+    pub id: String,
+    pub name: Option<(String, String)>,
+    pub description: bool, // the description was reworded
+    pub details: Vec<Change<String, Value>>, // by detail name
+}
+
+/// Everything that differs between two patches. IDs are sorted.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PatchDiff {
+    pub from: String,
+    pub to: String,
+    pub added_items: Vec<String>,
+    pub removed_items: Vec<String>,
+    pub changed_items: Vec<ItemChange>,
+    pub added_abilities: Vec<String>,
+    pub removed_abilities: Vec<String>,
+    pub changed_abilities: Vec<AbilityChange>,
+}
+
+// Values of two maps side by side, keeping only the keys whose value differs
+fn changes<K: Ord + Clone, V: PartialEq + Clone>(
+    before: impl IntoIterator<Item = (K, V)>,
+    after: impl IntoIterator<Item = (K, V)>,
+) -> Vec<Change<K, V>> {
+    let mut sides: BTreeMap<K, (Option<V>, Option<V>)> = BTreeMap::new();
+    for (key, value) in before {
+        sides.entry(key).or_default().0 = Some(value);
+    }
+    for (key, value) in after {
+        sides.entry(key).or_default().1 = Some(value);
+    }
+    sides.into_iter()
+        .filter(|(_, (before, after))| before != after)
+        .map(|(key, (before, after))| Change { key, before, after })
+        .collect()
+}
+
+fn sorted(ids: impl Iterator<Item = String>) -> Vec<String> {
+    let mut ids: Vec<String> = ids.collect();
+    ids.sort();
+    ids
+}
+
+/// How an item changed, `None` if players wouldn't notice (tags and build
+/// paths are ours, not the game's)
+pub fn item_change(id: &str, before: &Item, after: &Item) -> Option<ItemChange> {
+    let change = ItemChange {
+        id: id.to_string(),
+        name: (before.display_name != after.display_name)
+            .then(|| (before.display_name.clone(), after.display_name.clone())),
+        price: (before.price != after.price).then_some((before.price, after.price)),
+        stats: changes(before.stats.clone(), after.stats.clone()),
+        effects: changes(before.effects.clone(), after.effects.clone()),
+    };
+    let changed = change.name.is_some() || change.price.is_some()
+        || !change.stats.is_empty() || !change.effects.is_empty();
+    changed.then_some(change)
+}
+
+pub fn ability_change(id: &str, before: &Ability, after: &Ability) -> Option<AbilityChange> {
+    let change = AbilityChange {
+        id: id.to_string(),
+        name: (before.display_name != after.display_name)
+            .then(|| (before.display_name.clone(), after.display_name.clone())),
+        description: before.description != after.description,
+        details: changes(before.details.clone(), after.details.clone()),
+    };
+    (change.name.is_some() || change.description || !change.details.is_empty()).then_some(change)
+}
+
+/// Added, removed and changed items between two item tables
+pub fn diff_items(
+    before: &HashMap<String, Item>,
+    after: &HashMap<String, Item>,
+) -> (Vec<String>, Vec<String>, Vec<ItemChange>) {
+    let added = sorted(after.keys().filter(|id| !before.contains_key(*id)).cloned());
+    let removed = sorted(before.keys().filter(|id| !after.contains_key(*id)).cloned());
+    let mut changed: Vec<ItemChange> = before.iter()
+        .filter_map(|(id, old)| item_change(id, old, after.get(id)?))
+        .collect();
+    changed.sort_by(|a, b| a.id.cmp(&b.id));
+    (added, removed, changed)
+}
+
+/// Added, removed and changed abilities between two ability tables
+pub fn diff_abilities(
+    before: &HashMap<String, Ability>,
+    after: &HashMap<String, Ability>,
+) -> (Vec<String>, Vec<String>, Vec<AbilityChange>) {
+    let added = sorted(after.keys().filter(|id| !before.contains_key(*id)).cloned());
+    let removed = sorted(before.keys().filter(|id| !after.contains_key(*id)).cloned());
+    let mut changed: Vec<AbilityChange> = before.iter()
+        .filter_map(|(id, old)| ability_change(id, old, after.get(id)?))
+        .collect();
+    changed.sort_by(|a, b| a.id.cmp(&b.id));
+    (added, removed, changed)
+}
+
+pub fn diff_patches(from: &PatchData, to: &PatchData) -> PatchDiff {
+    let (added_items, removed_items, changed_items) = diff_items(from.items, to.items);
+    let (added_abilities, removed_abilities, changed_abilities) = diff_abilities(from.abilities, to.abilities);
+    PatchDiff {
+        from: from.id.to_string(),
+        to: to.id.to_string(),
+        added_items,
+        removed_items,
+        changed_items,
+        added_abilities,
+        removed_abilities,
+        changed_abilities,
+    }
+}
+
+// Diff from every older bundled patch to the current one, worked out on first use
+static DIFFS_TO_CURRENT: Lazy<HashMap<&'static str, PatchDiff>> = Lazy::new(|| {
+    PATCHES.iter()
+        .filter(|patch| patch.id != current_patch().id)
+        .map(|patch| (patch.id, diff_patches(patch, current_patch())))
+        .collect()
+});
+
+/// What changed from a bundled patch to the current one, `None` for the
+/// current patch and patches this build doesn't ship
+pub fn diff_to_current(from: &str) -> Option<&'static PatchDiff> {
+    DIFFS_TO_CURRENT.get(from)
+}
+
+/// Ability detail values as the wiki writes them ("120s", not "\"120s\"")
+pub fn format_detail(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// One side of a change, "-" where it doesn't exist
+pub fn side<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(ToString::to_string).unwrap_or_else(|| "-".to_string())
+}
+
+impl PatchDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_items.is_empty() && self.removed_items.is_empty() && self.changed_items.is_empty()
+            && self.added_abilities.is_empty() && self.removed_abilities.is_empty()
+            && self.changed_abilities.is_empty()
+    }
+
+    /// Plain text patch notes, one line per change
+    pub fn report(&self) -> String {
+        let mut out = format!("Patch {} -> {}\n", self.from, self.to);
+        if self.is_empty() {
+            out.push_str("No changes\n");
+        }
+
+        for id in &self.added_items {
+            let _ = writeln!(out, "+ item {}", id);
+        }
+        for id in &self.removed_items {
+            let _ = writeln!(out, "- item {}", id);
+        }
+        for item in &self.changed_items {
+            let _ = writeln!(out, "~ item {}", item.id);
+            if let Some((before, after)) = &item.name {
+                let _ = writeln!(out, "    name: {} -> {}", before, after);
+            }
+            if let Some((before, after)) = item.price {
+                let _ = writeln!(out, "    price: {} -> {}", before, after);
+            }
+            for stat in &item.stats {
+                let _ = writeln!(out, "    {}: {} -> {}", format_stat_label(&stat.key), side(&stat.before), side(&stat.after));
+            }
+            for effect in &item.effects {
+                let _ = writeln!(out, "    {:?} effect text changed", effect.key);
+            }
+        }
+
+        for id in &self.added_abilities {
+            let _ = writeln!(out, "+ ability {}", id);
+        }
+        for id in &self.removed_abilities {
+            let _ = writeln!(out, "- ability {}", id);
+        }
+        for ability in &self.changed_abilities {
+            let _ = writeln!(out, "~ ability {}", ability.id);
+            if let Some((before, after)) = &ability.name {
+                let _ = writeln!(out, "    name: {} -> {}", before, after);
+            }
+            if ability.description {
+                let _ = writeln!(out, "    description changed");
+            }
+            for detail in &ability.details {
+                let before = detail.before.as_ref().map(format_detail);
+                let after = detail.after.as_ref().map(format_detail);
+                let _ = writeln!(out, "    {}: {} -> {}", detail.key, side(&before), side(&after));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::patches::find_patch;
+    use serde_json::json;
+
+    fn ability(description: &str, details: Value) -> Ability {
+        Ability {
+            display_name: "Flame Wave".to_string(),
+            description: description.to_string(),
+            details: serde_json::from_value(details).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_item_diff() {
+        let mut before: HashMap<String, Item> = serde_json::from_value(json!({
+            "rod": {"display_name": "Rod", "price": 2500, "stats": {"MagicalPower": 60, "Mana": 200}},
+            "old": {"display_name": "Old", "price": 100},
+            "same": {"display_name": "Same", "price": 100, "tags": ["Tier1"]},
+        })).unwrap();
+        let after: HashMap<String, Item> = serde_json::from_value(json!({
+            "rod": {"display_name": "Rod", "price": 2600, "stats": {"MagicalPower": 70, "CooldownReduction": 10},
+                    "effects": {"Passive": "Burn"}},
+            "new": {"display_name": "New", "price": 100},
+            "same": {"display_name": "Same", "price": 100, "tags": ["Tier2"]},
+        })).unwrap();
+
+        let (added, removed, changed) = diff_items(&before, &after);
+        assert_eq!(added, vec!["new"]);
+        assert_eq!(removed, vec!["old"]);
+        assert_eq!(changed.len(), 1);
+        let rod = &changed[0];
+        assert_eq!(rod.price, Some((2500, 2600)));
+        assert_eq!(rod.stats, vec![
+            Change { key: ItemStat::MagicalPower, before: Some(60), after: Some(70) },
+            Change { key: ItemStat::Mana, before: Some(200), after: None },
+            Change { key: ItemStat::CooldownReduction, before: None, after: Some(10) },
+        ]);
+        assert_eq!(rod.effects, vec![Change { key: Effect::Passive, before: None, after: Some("Burn".to_string()) }]);
+
+        before.remove("old");
+        assert!(diff_items(&before, &before).2.is_empty());
+    }
+
+    #[test]
+    fn test_ability_diff() {
+        let before = HashMap::from([("agni_1".to_string(), ability("Burn", json!({"cooldown": "14s", "damage": 80})))]);
+        let after = HashMap::from([("agni_1".to_string(), ability("Burn", json!({"cooldown": "12s", "range": "55"})))]);
+
+        let (_, _, changed) = diff_abilities(&before, &after);
+        assert!(!changed[0].description);
+        let details: Vec<(&str, Option<String>, Option<String>)> = changed[0].details.iter()
+            .map(|detail| (detail.key.as_str(), detail.before.as_ref().map(format_detail), detail.after.as_ref().map(format_detail)))
+            .collect();
+        assert_eq!(details, vec![
+            ("cooldown", Some("14s".to_string()), Some("12s".to_string())),
+            ("damage", Some("80".to_string()), None),
+            ("range", None, Some("55".to_string())),
+        ]);
+    }
+
+    // `cargo test patch_notes -- --nocapture` prints the notes for the bundled patches
+    #[test]
+    fn test_patch_notes() {
+        let diff = diff_patches(find_patch("5.0").unwrap(), current_patch());
+        println!("{}", diff.report());
+
+        // The 5.0 passive of Protector's Mask was reworded in 5.1
+        assert!(diff.changed_items.iter().any(|item| item.id == "protectors_mask"));
+        assert!(!diff.changed_items.iter().any(|item| item.id == "rod_of_tahuti"));
+        assert!(diff.changed_items.iter().all(|item| item.effects.iter().all(|effect| effect.before != effect.after)));
+        assert!(diff.report().contains("~ item protectors_mask\n    Passive effect text changed"));
+        assert!(diff_patches(current_patch(), current_patch()).report().contains("No changes"));
+        assert_eq!(diff_to_current("5.0"), Some(&diff));
+        assert!(diff_to_current(current_patch().id).is_none());
+    }
+}
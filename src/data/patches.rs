@@ -0,0 +1,78 @@
+use std::collections::{BTreeMap, HashMap};
+use once_cell::sync::Lazy;
+use crate::data::abilities::{Ability, ABILITIES};
+use crate::data::gods::{God, GODS};
+use crate::data::guides::Guide;
+use crate::data::items::{Item, ITEMS};
+use crate::data::registry::DATA;
+
+/// Game data as it was on one patch
+pub struct PatchData {
+    pub id: &'static str,
+    pub items: &'static HashMap<String, Item>,
+    pub gods: &'static BTreeMap<String, God>,
+    pub abilities: &'static HashMap<String, Ability>,
+}
+
+/// Every bundled patch, oldest first. The last one is the current data.
+pub static PATCHES: Lazy<Vec<PatchData>> = Lazy::new(|| {
+    let mut patches: Vec<PatchData> = DATA.snapshots.iter()
+        .map(|snapshot| PatchData {
+            id: &snapshot.patch.patch,
+            items: &snapshot.items,
+            gods: &snapshot.gods,
+            abilities: &snapshot.abilities,
+        })
+        .collect();
+    patches.push(PatchData { id: &DATA.patch.patch, items: &ITEMS, gods: &GODS, abilities: &ABILITIES });
+    patches
+});
+
+pub fn find_patch(id: &str) -> Option<&'static PatchData> {
+    PATCHES.iter().find(|patch| patch.id == id)
+}
+
+pub fn current_patch() -> &'static PatchData {
+    PATCHES.last().expect("no bundled patches")
+}
+
+/// Bundled patch the guide was written on, if it's older than the current one.
+/// Guides that don't say are taken to be from the oldest bundled patch; ones
+/// on a patch this build doesn't ship aren't flagged.
+pub fn older_patch(guide: &Guide) -> Option<&'static PatchData> {
+    let patch = match guide.patch.as_deref() {
+        Some(id) => find_patch(id)?,
+        None => PATCHES.first()?,
+    };
+    (patch.id != current_patch().id).then_some(patch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::guides::GUIDES;
+
+    #[test]
+    fn test_bundled_patches_load() {
+        assert_eq!(current_patch().id, DATA.patch.patch);
+        for patch in PATCHES.iter() {
+            assert!(!patch.items.is_empty(), "{}", patch.id);
+            assert!(!patch.gods.is_empty(), "{}", patch.id);
+            assert!(!patch.abilities.is_empty(), "{}", patch.id);
+        }
+        // Curated guides don't record the patch they were written on
+        assert!(GUIDES.values().flatten().all(|guide| guide.patch.is_none()));
+    }
+
+    #[test]
+    fn test_older_patch() {
+        let mut guide = Guide::new("agni", "mid");
+        assert!(older_patch(&guide).is_none());
+        guide.patch = Some("5.0".to_string());
+        assert_eq!(older_patch(&guide).map(|patch| patch.id), Some("5.0"));
+        guide.patch = Some("9.9".to_string());
+        assert!(older_patch(&guide).is_none());
+        guide.patch = None;
+        assert_eq!(older_patch(&guide).map(|patch| patch.id), Some("5.0"));
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use crate::data::abilities::Ability;
+use crate::data::aliases::Aliases;
+use crate::data::gods::God;
+use crate::data::guides::Guide;
+use crate::data::integrity::check_references;
+use crate::data::items::Item;
+
+/// A bundled JSON file
+#[derive(Debug, Clone, Copy)]
+pub struct Source {
+    pub file: &'static str,
+    pub json: &'static str,
+}
+
+macro_rules! bundled {
+    ($file:literal) => {
+        Source { file: $file, json: include_str!(concat!("json/", $file)) }
+    };
+}
+
+/// Patch, item, god and ability files of an earlier patch
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotSources {
+    pub patch: Source,
+    pub items: Source,
+    pub gods: Source,
+    pub abilities: Source,
+}
+
+/// Every JSON file the app reads
+#[derive(Debug, Clone)]
+pub struct Sources {
+    pub patch: Source,
+    pub items: Source,
+    pub gods: Source,
+    pub abilities: Source,
+    pub guides: Source,
+    pub aliases: Source,
+    pub skill_order: Source,
+    pub snapshots: Vec<SnapshotSources>, // oldest first
+}
+
+impl Sources {
+    pub fn bundled() -> Sources {
+        Sources {
+            patch: bundled!("patch.json"),
+            items: bundled!("items.json"),
+            gods: bundled!("gods.json"),
+            abilities: bundled!("abilities.json"),
+            guides: bundled!("guides.json"),
+            aliases: bundled!("aliases.json"),
+            skill_order: bundled!("skill_order.json"),
+            // The previous patch, kept next to the current data as *.json.backup
+            snapshots: vec![SnapshotSources {
+                patch: bundled!("patch.json.backup"),
+                items: bundled!("items.json.backup"),
+                gods: bundled!("gods.json.backup"),
+                abilities: bundled!("abilities.json.backup"),
+            }],
+        }
+    }
+}
+
+/// Something wrong with the bundled data
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataError {
+    pub file: &'static str,
+    pub path: String, // JSON path of the bad value, "." for the whole file
+    pub reason: String,
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}: {}", self.file, self.path, self.reason)
+    }
+}
+
+/// The game patch a set of item, god and ability files describes
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PatchInfo {
+    pub patch: String,
+}
+
+/// Items, gods and abilities of an earlier patch
+#[derive(Debug, Clone, Default)]
+pub struct Snapshot {
+    pub patch: PatchInfo,
+    pub items: HashMap<String, Item>,
+    pub gods: BTreeMap<String, God>,
+    pub abilities: HashMap<String, Ability>,
+}
+
+/// All game data and guides. A file that fails to parse is left empty and
+/// its problem recorded, so the app can explain what broke instead of panicking.
+#[derive(Debug, Clone, Default)]
+pub struct DataRegistry {
+    pub patch: PatchInfo, // of items, gods and abilities
+    pub items: HashMap<String, Item>,
+    pub gods: BTreeMap<String, God>,
+    pub abilities: HashMap<String, Ability>,
+    pub guides: HashMap<String, Vec<Guide>>,
+    pub aliases: Aliases,
+    pub skill_order: HashMap<String, Vec<u8>>,
+    pub snapshots: Vec<Snapshot>,
+    pub load_errors: Vec<DataError>,      // files that couldn't be read
+    pub reference_errors: Vec<DataError>, // IDs pointing at nothing
+}
+
+/// Parse a bundled file, naming the JSON path of the value that didn't fit
+pub fn parse<T: DeserializeOwned>(source: Source) -> Result<T, DataError> {
+    let deserializer = &mut serde_json::Deserializer::from_str(source.json);
+    serde_path_to_error::deserialize(deserializer).map_err(|error| DataError {
+        file: source.file,
+        path: error.path().to_string(),
+        reason: error.into_inner().to_string(),
+    })
+}
+
+fn load<T: DeserializeOwned + Default>(source: Source, errors: &mut Vec<DataError>) -> T {
+    parse(source).unwrap_or_else(|error| {
+        errors.push(error);
+        T::default()
+    })
+}
+
+impl DataRegistry {
+    pub fn load(sources: &Sources) -> DataRegistry {
+        let mut errors = Vec::new();
+        let mut registry = DataRegistry {
+            patch: load(sources.patch, &mut errors),
+            items: load(sources.items, &mut errors),
+            gods: load(sources.gods, &mut errors),
+            abilities: load(sources.abilities, &mut errors),
+            guides: load(sources.guides, &mut errors),
+            aliases: load(sources.aliases, &mut errors),
+            skill_order: load(sources.skill_order, &mut errors),
+            snapshots: sources.snapshots.iter().map(|snapshot| Snapshot {
+                patch: load(snapshot.patch, &mut errors),
+                items: load(snapshot.items, &mut errors),
+                gods: load(snapshot.gods, &mut errors),
+                abilities: load(snapshot.abilities, &mut errors),
+            }).collect(),
+            load_errors: Vec::new(),
+            reference_errors: Vec::new(),
+        };
+        registry.load_errors = errors;
+        registry.reference_errors = check_references(&registry);
+        registry
+    }
+
+    /// Whether a file failed to load, leaving the app without data it needs
+    pub fn is_broken(&self) -> bool {
+        !self.load_errors.is_empty()
+    }
+}
+
+/// The bundled data, loaded on first use
+pub static DATA: Lazy<DataRegistry> = Lazy::new(|| DataRegistry::load(&Sources::bundled()));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(file: &'static str, json: &'static str) -> Source {
+        Source { file, json }
+    }
+
+    #[test]
+    fn test_bundled_data_loads() {
+        assert!(DATA.load_errors.is_empty(), "{:?}", DATA.load_errors);
+        assert!(DATA.reference_errors.is_empty(), "{:?}", DATA.reference_errors);
+        assert!(!DATA.items.is_empty() && !DATA.gods.is_empty() && !DATA.skill_order.is_empty());
+        assert_eq!(DATA.patch.patch, "5.1");
+        assert_eq!(DATA.snapshots[0].patch.patch, "5.0");
+    }
+
+    #[test]
+    fn test_errors_name_file_and_path() {
+        let error = parse::<HashMap<String, Item>>(source("items.json", r#"{"rod": {"display_name": "Rod", "price": "cheap"}}"#)).unwrap_err();
+        assert_eq!(error.file, "items.json");
+        assert_eq!(error.path, "rod.price");
+        assert!(error.reason.contains("invalid type"), "{}", error.reason);
+
+        let error = parse::<HashMap<String, Item>>(source("items.json", r#"{"rod": {"display_name": "Rod", "price": 1,}}"#)).unwrap_err();
+        assert!(error.reason.contains("line 1"), "{}", error);
+        assert_eq!(error.to_string(), format!("items.json at {}: {}", error.path, error.reason));
+    }
+
+    #[test]
+    fn test_broken_file_is_reported_not_fatal() {
+        let mut sources = Sources::bundled();
+        sources.items = source("items.json", "{ not json");
+        let registry = DataRegistry::load(&sources);
+
+        assert!(registry.is_broken());
+        assert_eq!(registry.load_errors.len(), 1);
+        assert!(registry.items.is_empty());
+        assert!(!registry.gods.is_empty());
+        // With no items, every guide item is a dangling reference
+        assert!(registry.reference_errors.iter().any(|error| error.path == "agni[0].build[0]"));
+    }
+}
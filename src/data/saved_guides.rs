@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+use crate::data::guides::{Guide, GUIDE_SCHEMA_VERSION};
+use crate::data::migrations::{migrate_guide_value, schema_version};
+use crate::utils::{clear_from_storage, load_from_storage, save_to_storage, storage_keys};
+
+/// localStorage key prefix for guides saved from the creator
+pub const SAVED_GUIDE_PREFIX: &str = "grappul_build_";
+
+/// localStorage key of the creator's autosaved draft
+const DRAFT_KEY: &str = "grappul_draft";
+
+/// A user guide stored in localStorage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedGuide {
+    pub id: String,
+    pub created_at: String,
+    #[serde(flatten)]
+    pub guide: Guide,
+}
+
+impl SavedGuide {
+    /// A new saved guide with a timestamp-based ID
+    pub fn new(guide: Guide, timestamp: u64) -> Self {
+        SavedGuide {
+            id: format!("build_{}", timestamp),
+            created_at: timestamp.to_string(),
+            guide,
+        }
+    }
+
+    pub fn storage_key(&self) -> String {
+        storage_key(&self.id)
+    }
+}
+
+fn storage_key(id: &str) -> String {
+    format!("grappul_{}", id)
+}
+
+/// Parse a stored guide, migrating older schema versions.
+/// The flag is true when the stored JSON was in an older format.
+pub fn parse_saved_guide(json: &str) -> Result<(SavedGuide, bool), String> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let outdated = schema_version(&value) < GUIDE_SCHEMA_VERSION;
+    let value = migrate_guide_value(value)?;
+    let saved = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    Ok((saved, outdated))
+}
+
+pub fn save_guide(saved: &SavedGuide) {
+    if let Ok(json) = serde_json::to_string_pretty(saved) {
+        save_to_storage(&saved.storage_key(), &json);
+    }
+}
+
+pub fn delete_saved_guide(id: &str) {
+    clear_from_storage(&storage_key(id));
+    clear_draft(Some(id));
+}
+
+/// Unsaved creator state, autosaved so a refresh doesn't lose it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Draft {
+    /// The saved guide this draft will overwrite, if it has been saved before
+    pub saved_id: Option<String>,
+    pub guide: Guide,
+}
+
+/// Drafts for new guides share one slot; each saved guide being edited has its own
+fn draft_key(editing: Option<&str>) -> String {
+    match editing {
+        Some(id) => format!("{}_{}", DRAFT_KEY, id),
+        None => DRAFT_KEY.to_string(),
+    }
+}
+
+/// Parse a stored draft, migrating the guide inside it
+pub fn parse_draft(json: &str) -> Result<Draft, String> {
+    let mut value: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    if let Some(guide) = value.get_mut("guide") {
+        *guide = migrate_guide_value(guide.take())?;
+    }
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+pub fn save_draft(editing: Option<&str>, draft: &Draft) {
+    if let Ok(json) = serde_json::to_string(draft) {
+        save_to_storage(&draft_key(editing), &json);
+    }
+}
+
+pub fn load_draft(editing: Option<&str>) -> Option<Draft> {
+    let json = load_from_storage(&draft_key(editing))?;
+    match parse_draft(&json) {
+        Ok(draft) => Some(draft),
+        Err(e) => {
+            log::warn!("Dropping unreadable draft: {}", e);
+            clear_draft(editing);
+            None
+        }
+    }
+}
+
+pub fn clear_draft(editing: Option<&str>) {
+    clear_from_storage(&draft_key(editing));
+}
+
+/// Every saved guide in localStorage, oldest first.
+/// Guides in an older format are upgraded and written back, under the key
+/// their ID maps to; guides saved before that key scheme sat at
+/// `grappul_build_build_<ts>`, and that key is removed once it's moved.
+pub fn load_saved_guides() -> Vec<SavedGuide> {
+    let keys = storage_keys(SAVED_GUIDE_PREFIX);
+    let mut guides: Vec<SavedGuide> = keys.iter()
+        .filter_map(|key| {
+            let json = load_from_storage(key)?;
+            match parse_saved_guide(&json) {
+                Ok((saved, outdated)) => {
+                    let target = saved.storage_key();
+                    if *key != target {
+                        clear_from_storage(key);
+                        // A copy already at the new key is the newer one
+                        if keys.contains(&target) {
+                            return None;
+                        }
+                        log::info!("Moved {} to {}", key, target);
+                        save_guide(&saved);
+                    } else if outdated {
+                        log::info!("Migrated {} to schema version {}", key, GUIDE_SCHEMA_VERSION);
+                        save_guide(&saved);
+                    }
+                    Some(saved)
+                }
+                Err(e) => {
+                    log::warn!("Skipping saved guide {}: {}", key, e);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    guides.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    guides
+}
+
+/// The saved guides, read from localStorage once and shared through context.
+/// Saving and deleting go through here so every view stays in sync.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SavedGuides(pub Vec<SavedGuide>);
+
+impl SavedGuides {
+    pub fn get(&self, id: &str) -> Option<&SavedGuide> {
+        self.0.iter().find(|saved| saved.id == id)
+    }
+
+    /// Write a guide to localStorage, replacing the one with the same ID
+    pub fn save(&mut self, saved: SavedGuide) {
+        save_guide(&saved);
+        self.insert(saved);
+    }
+
+    pub fn delete(&mut self, id: &str) {
+        delete_saved_guide(id);
+        self.0.retain(|saved| saved.id != id);
+    }
+
+    fn insert(&mut self, saved: SavedGuide) {
+        match self.0.iter_mut().find(|existing| existing.id == saved.id) {
+            Some(existing) => *existing = saved,
+            None => {
+                self.0.push(saved);
+                self.0.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saved_guide_keeps_storage_fields() {
+        let saved = SavedGuide::new(Guide::new("agni", "mid"), 1700000000000);
+        assert_eq!(saved.storage_key(), "grappul_build_1700000000000");
+
+        let json = serde_json::to_string(&saved).unwrap();
+        let (parsed, outdated) = parse_saved_guide(&json).unwrap();
+        assert_eq!(parsed, saved);
+        assert!(!outdated);
+    }
+
+    #[test]
+    fn test_old_saved_build_is_flagged_for_rewrite() {
+        let json = r#"{"id": "build_1", "title": "", "god": "agni", "role": "Mid", "build": [], "relics": [],
+            "timeline": [], "skill_order": [], "strategy": "", "created_at": "1"}"#;
+        let (parsed, outdated) = parse_saved_guide(json).unwrap();
+        assert!(outdated);
+        assert_eq!(parsed.id, "build_1");
+        assert_eq!(parsed.guide.god_id, "agni");
+    }
+
+    #[test]
+    fn test_draft_round_trip() {
+        let draft = Draft { saved_id: Some("build_1".to_string()), guide: Guide::new("agni", "mid") };
+        assert_eq!(parse_draft(&serde_json::to_string(&draft).unwrap()).unwrap(), draft);
+        assert_eq!(draft_key(None), "grappul_draft");
+        assert_eq!(draft_key(Some("build_1")), "grappul_draft_build_1");
+    }
+
+    #[test]
+    fn test_insert_replaces_by_id() {
+        let mut guides = SavedGuides::default();
+        guides.insert(SavedGuide::new(Guide::new("zeus", "mid"), 2));
+        guides.insert(SavedGuide::new(Guide::new("agni", "mid"), 1));
+        let mut edited = SavedGuide::new(Guide::new("agni", "jungle"), 1);
+        edited.guide.title = Some("Burn".to_string());
+        guides.insert(edited.clone());
+
+        assert_eq!(guides.0.len(), 2);
+        assert_eq!(guides.0[0], edited);
+        assert_eq!(guides.0[1].id, "build_2");
+    }
+
+    #[test]
+    fn test_legacy_keys_are_moved_once() {
+        let legacy = r#"{"id": "build_1700000000000", "title": "", "god": "agni", "role": "Mid", "build": [], "relics": [],
+            "timeline": [], "skill_order": [], "strategy": "", "created_at": "1700000000000"}"#;
+        save_to_storage("grappul_build_build_1700000000000", legacy);
+
+        let guides = load_saved_guides();
+        assert_eq!(guides.len(), 1);
+        assert_eq!(storage_keys(SAVED_GUIDE_PREFIX), vec!["grappul_build_1700000000000"]);
+        assert_eq!(load_saved_guides(), guides);
+
+        delete_saved_guide(&guides[0].id);
+        assert!(storage_keys("grappul_").is_empty());
+        assert!(load_saved_guides().is_empty());
+
+        // Both copies left by the old migration: the one at the new key wins
+        let mut newer = guides[0].clone();
+        newer.guide.title = Some("Edited".to_string());
+        save_guide(&newer);
+        save_to_storage("grappul_build_build_1700000000000", legacy);
+        assert_eq!(load_saved_guides(), vec![newer]);
+        assert_eq!(storage_keys(SAVED_GUIDE_PREFIX), vec!["grappul_build_1700000000000"]);
+    }
+}
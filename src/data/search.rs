@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+use once_cell::sync::Lazy;
+use crate::data::abilities::ABILITIES;
+use crate::data::aliases::{resolve_item_alias, ALIASES};
+use crate::data::gods::GODS;
+use crate::data::guide_sources::curated_id;
+use crate::data::guides::GUIDES;
+use crate::data::items::ITEMS;
+
+/// What a search result opens
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchTarget {
+    God(String),
+    Item(String),
+    Ability { god: String, ability: String },
+    Guide { god: String, role: String, id: String }, // id matches `GuideEntry::id`
+}
+
+impl SearchTarget {
+    /// Short kind label shown next to a result
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SearchTarget::God(_) => "God",
+            SearchTarget::Item(_) => "Item",
+            SearchTarget::Ability { .. } => "Ability",
+            SearchTarget::Guide { .. } => "Guide",
+        }
+    }
+
+    // Tie-break between equally scored results
+    fn rank(&self) -> u8 {
+        match self {
+            SearchTarget::God(_) => 0,
+            SearchTarget::Item(_) => 1,
+            SearchTarget::Ability { .. } => 2,
+            SearchTarget::Guide { .. } => 3,
+        }
+    }
+}
+
+/// One searchable thing. Names and text are stored normalized.
+#[derive(Debug, Clone)]
+pub struct SearchDoc {
+    pub target: SearchTarget,
+    pub title: String,
+    pub detail: String,
+    names: Vec<String>,    // display name, ID and aliases
+    words: Vec<String>,    // distinct words of the long text (descriptions, strategy)
+    mentions: Vec<String>, // names of the items a guide uses
+    mention_ids: Vec<String>,
+}
+
+/// Index over every god, item, ability and curated guide, built on first use
+pub static SEARCH_INDEX: Lazy<Vec<SearchDoc>> = Lazy::new(build_index);
+
+fn build_index() -> Vec<SearchDoc> {
+    let mut docs = Vec::new();
+
+    let mut ability_gods: HashMap<&str, &str> = HashMap::new();
+    for (god_id, god) in GODS.iter() {
+        for ability in &god.abilities {
+            ability_gods.insert(ability.as_str(), god_id.as_str());
+        }
+
+        docs.push(SearchDoc {
+            target: SearchTarget::God(god_id.clone()),
+            title: god.display_name.clone(),
+            detail: [god.class.as_str(), god.pantheon.as_str()]
+                .iter()
+                .filter(|part| !part.is_empty())
+                .copied()
+                .collect::<Vec<_>>()
+                .join(" · "),
+            names: names_of(&god.display_name, god_id, &[]),
+            words: words_of(&god.title),
+            mentions: Vec::new(),
+            mention_ids: Vec::new(),
+        });
+    }
+
+    for (item_id, item) in ITEMS.iter() {
+        let aliases = ALIASES.items.get(item_id).map(Vec::as_slice).unwrap_or_default();
+        let effects: Vec<&str> = item.effects.values().map(String::as_str).collect();
+        docs.push(SearchDoc {
+            target: SearchTarget::Item(item_id.clone()),
+            title: item.display_name.clone(),
+            detail: if item.price > 0 { format!("{} gold", item.price) } else { String::new() },
+            names: names_of(&item.display_name, item_id, aliases),
+            words: words_of(&effects.join(" ")),
+            mentions: Vec::new(),
+            mention_ids: Vec::new(),
+        });
+    }
+
+    for (ability_id, ability) in ABILITIES.iter() {
+        // Abilities no god lists have nowhere to jump to
+        let Some(god_id) = ability_gods.get(ability_id.as_str()) else {
+            continue;
+        };
+        let aliases = ALIASES.abilities.get(ability_id).map(Vec::as_slice).unwrap_or_default();
+        docs.push(SearchDoc {
+            target: SearchTarget::Ability { god: god_id.to_string(), ability: ability_id.clone() },
+            title: ability.display_name.clone(),
+            detail: god_name(god_id),
+            names: names_of(&ability.display_name, ability_id, aliases),
+            words: words_of(&ability.description),
+            mentions: Vec::new(),
+            mention_ids: Vec::new(),
+        });
+    }
+
+    for (god_id, guides) in GUIDES.iter() {
+        for guide in guides.iter() {
+            let title = guide.title.clone()
+                .unwrap_or_else(|| format!("{} {}", god_name(god_id), guide.role));
+
+            let timeline_items = guide.timeline.iter().flat_map(|entry| entry.items.iter().flatten());
+            let mut mention_ids: Vec<String> = guide.build.iter()
+                .chain(&guide.relics)
+                .chain(timeline_items)
+                .map(|item| resolve_item_alias(item))
+                .collect();
+            mention_ids.sort();
+            mention_ids.dedup();
+            let mentions = mention_ids.iter()
+                .filter_map(|id| ITEMS.get(id))
+                .map(|item| normalize(&item.display_name))
+                .collect();
+
+            docs.push(SearchDoc {
+                target: SearchTarget::Guide { god: god_id.clone(), role: guide.role.clone(), id: curated_id(god_id, &guide.role) },
+                detail: format!("{} guide", guide.role),
+                names: vec![normalize(&title)],
+                title,
+                words: words_of(guide.strategy.as_deref().unwrap_or_default()),
+                mentions,
+                mention_ids,
+            });
+        }
+    }
+
+    docs
+}
+
+fn god_name(god_id: &str) -> String {
+    GODS.get(god_id)
+        .map(|god| god.display_name.clone())
+        .unwrap_or_else(|| god_id.to_string())
+}
+
+fn names_of(display_name: &str, id: &str, aliases: &[String]) -> Vec<String> {
+    let mut names: Vec<String> = [display_name, id].into_iter()
+        .chain(aliases.iter().map(String::as_str))
+        .map(normalize)
+        .filter(|name| !name.is_empty())
+        .collect();
+    names.dedup();
+    names
+}
+
+fn words_of(text: &str) -> Vec<String> {
+    let mut words: Vec<String> = normalize(text).split(' ').map(str::to_string).collect();
+    words.sort();
+    words.dedup();
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+/// Lowercase, drop apostrophes and turn any other punctuation (including `_`)
+/// into single spaces, so "Banshee's Wail" and "banshees_wail" compare equal
+pub fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            normalized.extend(c.to_lowercase());
+        } else if c != '\'' && c != '’' && !normalized.ends_with(' ') {
+            normalized.push(' ');
+        }
+    }
+    normalized.trim().to_string()
+}
+
+/// Edit distance counting adjacent swaps as one edit
+fn typo_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+// Typos tolerated in a query word of this many characters
+fn allowed_typos(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=6 => 1,
+        _ => 2,
+    }
+}
+
+// Fewest typos between a query word and any name word (or the start of one,
+// for words still being typed)
+fn word_typos(token: &str, words: &[&str]) -> Option<usize> {
+    let allowed = allowed_typos(token.chars().count());
+    words.iter()
+        .map(|word| {
+            let prefix: String = word.chars().take(token.chars().count()).collect();
+            typo_distance(token, word).min(typo_distance(token, &prefix))
+        })
+        .min()
+        .filter(|&typos| typos <= allowed)
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|h| h == c))
+}
+
+/// How well a normalized query matches a normalized name, higher is better.
+/// Exact and prefix matches beat substrings, which beat typo matches, which
+/// beat loose in-order character matches.
+pub fn name_score(query: &str, name: &str) -> Option<u32> {
+    if query.is_empty() || name.is_empty() {
+        return None;
+    }
+    if name == query {
+        return Some(1000);
+    }
+    if name.starts_with(query) {
+        // Prefer the shortest completion
+        return Some(900 - (name.len() - query.len()).min(100) as u32);
+    }
+
+    let words: Vec<&str> = name.split(' ').collect();
+    let tokens: Vec<&str> = query.split(' ').collect();
+    if words.iter().any(|word| word.starts_with(query)) {
+        return Some(700);
+    }
+    if tokens.len() > 1 && tokens.iter().all(|token| words.iter().any(|word| word.starts_with(token))) {
+        return Some(650);
+    }
+
+    let compact_query = query.replace(' ', "");
+    let compact_name = name.replace(' ', "");
+    if compact_name.contains(&compact_query) {
+        return Some(600);
+    }
+
+    let typos: Option<usize> = tokens.iter().map(|token| word_typos(token, &words)).sum();
+    if let Some(typos) = typos {
+        return Some(400u32.saturating_sub(50 * typos as u32).max(250));
+    }
+
+    if compact_query.len() >= 2 && is_subsequence(&compact_query, &compact_name) {
+        return Some(50 + (50 * compact_query.len() / compact_name.len()) as u32);
+    }
+    None
+}
+
+// Weakest match a filter box still shows: typos yes, loose in-order letters no
+const FILTER_SCORE: u32 = 250;
+
+/// Whether a filter box query matches any of the names. An empty query matches everything.
+pub fn fuzzy_matches(query: &str, names: &[&str]) -> bool {
+    let query = normalize(query);
+    query.is_empty() || names.iter().any(|name| {
+        name_score(&query, &normalize(name)).is_some_and(|score| score >= FILTER_SCORE)
+    })
+}
+
+// Guides using a strongly matching item rank under the item itself
+const MENTION_SCORE: u32 = 120;
+// Query words found in descriptions or strategy text
+const TEXT_SCORE: u32 = 80;
+
+impl SearchDoc {
+    /// Score of this document for a normalized query
+    fn score(&self, query: &str) -> Option<u32> {
+        let by_name = self.names.iter().filter_map(|name| name_score(query, name)).max();
+
+        let by_mention = self.mentions.iter()
+            .filter_map(|name| name_score(query, name))
+            .any(|score| score >= 600)
+            .then_some(MENTION_SCORE);
+
+        let tokens: Vec<&str> = query.split(' ').collect();
+        let by_text = (tokens.iter().all(|token| token.len() >= 3)
+            && tokens.iter().all(|token| self.words.iter().any(|word| word.starts_with(token))))
+            .then_some(TEXT_SCORE);
+
+        [by_name, by_mention, by_text].into_iter().flatten().max()
+    }
+}
+
+/// Best matches for `query`, best first
+pub fn search(query: &str, limit: usize) -> Vec<&'static SearchDoc> {
+    let query = normalize(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits: Vec<(u32, &SearchDoc)> = SEARCH_INDEX.iter()
+        .filter_map(|doc| doc.score(&query).map(|score| (score, doc)))
+        .collect();
+    hits.sort_by(|(score_a, a), (score_b, b)| {
+        score_b.cmp(score_a)
+            .then(a.target.rank().cmp(&b.target.rank()))
+            .then(a.title.len().cmp(&b.title.len()))
+            .then(a.title.cmp(&b.title))
+    });
+    hits.into_iter().take(limit).map(|(_, doc)| doc).collect()
+}
+
+/// Curated guides whose build, relics or timeline include the item
+pub fn guides_mentioning(item_id: &str) -> Vec<&'static SearchDoc> {
+    let item_id = resolve_item_alias(item_id);
+    let mut guides: Vec<&SearchDoc> = SEARCH_INDEX.iter()
+        .filter(|doc| doc.mention_ids.contains(&item_id))
+        .collect();
+    guides.sort_by(|a, b| a.title.cmp(&b.title));
+    guides
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first(query: &str) -> SearchTarget {
+        search(query, 1).first().map(|doc| doc.target.clone()).expect(query)
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("Banshee's Wail"), "banshees wail");
+        assert_eq!(normalize("rod_of_tahuti"), "rod of tahuti");
+        assert_eq!(normalize("  10-Hand  Shadow Fist "), "10 hand shadow fist");
+    }
+
+    #[test]
+    fn test_typo_distance() {
+        assert_eq!(typo_distance("tahuti", "tahuti"), 0);
+        assert_eq!(typo_distance("tahtui", "tahuti"), 1);
+        assert_eq!(typo_distance("tahut", "tahuti"), 1);
+        assert_eq!(typo_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_name_score_ordering() {
+        let exact = name_score("agni", "agni").unwrap();
+        let prefix = name_score("ag", "agni").unwrap();
+        let word = name_score("tahuti", "rod of tahuti").unwrap();
+        let typo = name_score("tahtui", "rod of tahuti").unwrap();
+        let loose = name_score("rdtht", "rod of tahuti").unwrap();
+        assert!(exact > prefix && prefix > word && word > typo && typo > loose);
+        assert_eq!(name_score("xyz", "agni"), None);
+        // Short words must match exactly
+        assert_eq!(name_score("agmi", "zeus"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_matches() {
+        assert!(fuzzy_matches("", &["agni"]));
+        assert!(fuzzy_matches("gni", &["agni"]));
+        assert!(fuzzy_matches("Ah Puch", &["ah_puch"]));
+        assert!(fuzzy_matches("tahtui", &["rod_of_tahuti"]));
+        assert!(!fuzzy_matches("rdtht", &["rod_of_tahuti"]));
+    }
+
+    #[test]
+    fn test_search_ranks_each_kind() {
+        assert_eq!(first("agni"), SearchTarget::God("agni".to_string()));
+        assert_eq!(first("rod of tahuti"), SearchTarget::Item("rod_of_tahuti".to_string()));
+        assert_eq!(first("tahtui"), SearchTarget::Item("rod_of_tahuti".to_string()));
+        assert_eq!(first("meteor"), SearchTarget::Ability { god: "agni".to_string(), ability: "rain_fire".to_string() });
+    }
+
+    #[test]
+    fn test_guides_are_found_by_item_and_strategy() {
+        let agni_mid = SearchTarget::Guide { god: "agni".to_string(), role: "mid".to_string(), id: curated_id("agni", "mid") };
+
+        assert!(guides_mentioning("spear_of_the_magus").iter().any(|doc| doc.target == agni_mid));
+        assert!(search("spear of the magus", 20).iter().any(|doc| doc.target == agni_mid));
+        assert!(search("synergizes", 20).iter().any(|doc| doc.target == agni_mid));
+        assert!(search("", 20).is_empty());
+    }
+}
@@ -0,0 +1,399 @@
+use std::collections::HashMap;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use crate::data::guides::{Guide, TimelineEntry, GUIDE_SCHEMA_VERSION};
+use crate::data::registry::{parse, Source};
+
+/// First byte of every share code. Bump it when the layout below changes.
+/// Version 1 codes are the same without the trailing patch.
+const SHARE_FORMAT_VERSION: u8 = 2;
+
+/// Roles stored as a single byte; anything else is written out as text
+const ROLE_CODES: [&str; 5] = ["solo", "jungle", "mid", "support", "adc"];
+const ROLE_TEXT: u8 = 0xFF;
+
+/// Skill order storage modes
+const SKILLS_PACKED: u8 = 0; // four 2-bit slots per byte, every point in 1-4
+const SKILLS_RAW: u8 = 1;    // one byte per point
+
+// Items and gods are referenced by their position in share_ids.json.
+// References are `index + 1`; 0 means the ID follows as text, so IDs missing
+// from the tables still round-trip. The tables are append-only: codes already
+// handed out keep pointing at the same IDs when items are added or removed.
+#[derive(Debug, Default, Deserialize)]
+struct ShareIds {
+    gods: Vec<String>,
+    items: Vec<String>,
+}
+
+static SHARE_IDS: Lazy<ShareIds> = Lazy::new(|| {
+    let source = Source { file: "share_ids.json", json: include_str!("json/share_ids.json") };
+    parse(source).unwrap_or_else(|error| {
+        // Everything is written out as text until the table loads again
+        log::error!("{}", error);
+        ShareIds::default()
+    })
+});
+static ITEM_TABLE: Lazy<IdTable> = Lazy::new(|| IdTable::new(&SHARE_IDS.items));
+static GOD_TABLE: Lazy<IdTable> = Lazy::new(|| IdTable::new(&SHARE_IDS.gods));
+
+struct IdTable {
+    ids: &'static [String],
+    positions: HashMap<&'static str, usize>,
+}
+
+impl IdTable {
+    fn new(ids: &'static [String]) -> Self {
+        let positions = ids.iter().enumerate().map(|(i, id)| (id.as_str(), i)).collect();
+        IdTable { ids, positions }
+    }
+}
+
+/// Encode a guide as a compact, URL-safe code for `/g/:code`
+pub fn encode_guide(guide: &Guide) -> String {
+    let mut writer = Writer::default();
+    writer.byte(SHARE_FORMAT_VERSION);
+
+    writer.reference(&GOD_TABLE, &guide.god_id);
+    match ROLE_CODES.iter().position(|&role| role == guide.role) {
+        Some(code) => writer.byte(code as u8),
+        None => {
+            writer.byte(ROLE_TEXT);
+            writer.text(&guide.role);
+        }
+    }
+    writer.optional_text(guide.title.as_deref());
+
+    writer.references(&ITEM_TABLE, &guide.build);
+    writer.references(&ITEM_TABLE, &guide.relics);
+
+    writer.varint(guide.skill_order.len());
+    if guide.skill_order.iter().all(|skill| (1..=4).contains(skill)) {
+        writer.byte(SKILLS_PACKED);
+        for chunk in guide.skill_order.chunks(4) {
+            let packed = chunk.iter().enumerate()
+                .fold(0u8, |byte, (i, &skill)| byte | ((skill - 1) << (i * 2)));
+            writer.byte(packed);
+        }
+    } else {
+        writer.byte(SKILLS_RAW);
+        writer.bytes.extend_from_slice(&guide.skill_order);
+    }
+
+    writer.varint(guide.timeline.len());
+    for entry in &guide.timeline {
+        writer.byte(entry.percent);
+        match &entry.items {
+            Some(items) => {
+                writer.byte(1);
+                writer.references(&ITEM_TABLE, items);
+            }
+            None => writer.byte(0),
+        }
+        writer.optional_text(entry.tip.as_deref());
+    }
+
+    writer.optional_text(guide.strategy.as_deref());
+    writer.optional_text(guide.patch.as_deref());
+    base64_encode(&writer.bytes)
+}
+
+/// Decode a code made by `encode_guide`
+pub fn decode_guide(code: &str) -> Result<Guide, String> {
+    let bytes = base64_decode(code.trim())?;
+    let mut reader = Reader { bytes: &bytes, pos: 0 };
+
+    let version = reader.byte()?;
+    if !(1..=SHARE_FORMAT_VERSION).contains(&version) {
+        return Err(format!("unsupported share code version {}", version));
+    }
+
+    let god_id = reader.reference(&GOD_TABLE)?;
+    let role = match reader.byte()? {
+        ROLE_TEXT => reader.text()?,
+        code => ROLE_CODES.get(code as usize)
+            .map(|role| role.to_string())
+            .ok_or_else(|| format!("unknown role code {}", code))?,
+    };
+    let title = reader.optional_text()?;
+
+    let build = reader.references(&ITEM_TABLE)?;
+    let relics = reader.references(&ITEM_TABLE)?;
+
+    let points = reader.varint()?;
+    let skill_order = match reader.byte()? {
+        SKILLS_PACKED => {
+            let packed = reader.take(points.div_ceil(4))?;
+            (0..points).map(|i| ((packed[i / 4] >> ((i % 4) * 2)) & 0b11) + 1).collect()
+        }
+        SKILLS_RAW => reader.take(points)?.to_vec(),
+        mode => return Err(format!("unknown skill order mode {}", mode)),
+    };
+
+    let entries = reader.varint()?;
+    let mut timeline = Vec::new();
+    for _ in 0..entries {
+        let percent = reader.byte()?;
+        let items = match reader.byte()? {
+            0 => None,
+            _ => Some(reader.references(&ITEM_TABLE)?),
+        };
+        let tip = reader.optional_text()?;
+        timeline.push(TimelineEntry { percent, items, tip });
+    }
+
+    let strategy = reader.optional_text()?;
+    let patch = if version >= 2 { reader.optional_text()? } else { None };
+    if reader.pos != bytes.len() {
+        return Err("unexpected data after the guide".to_string());
+    }
+
+    Ok(Guide {
+        schema_version: GUIDE_SCHEMA_VERSION,
+        title,
+        god_id,
+        role,
+        build,
+        relics,
+        timeline,
+        skill_order,
+        strategy,
+        patch,
+    })
+}
+
+#[derive(Default)]
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn byte(&mut self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
+    /// LEB128: seven bits per byte, high bit set while more follow
+    fn varint(&mut self, mut value: usize) {
+        while value >= 0x80 {
+            self.bytes.push((value as u8 & 0x7F) | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    fn text(&mut self, text: &str) {
+        self.varint(text.len());
+        self.bytes.extend_from_slice(text.as_bytes());
+    }
+
+    fn optional_text(&mut self, text: Option<&str>) {
+        match text {
+            Some(text) => {
+                self.byte(1);
+                self.text(text);
+            }
+            None => self.byte(0),
+        }
+    }
+
+    fn reference(&mut self, table: &IdTable, id: &str) {
+        match table.positions.get(id) {
+            Some(index) => self.varint(index + 1),
+            None => {
+                self.varint(0);
+                self.text(id);
+            }
+        }
+    }
+
+    fn references(&mut self, table: &IdTable, ids: &[String]) {
+        self.varint(ids.len());
+        for id in ids {
+            self.reference(table, id);
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.pos.checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or("share code is truncated")?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> Result<usize, String> {
+        let mut value = 0usize;
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7F) as usize) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("number in share code is too long".to_string())
+    }
+
+    fn text(&mut self) -> Result<String, String> {
+        let len = self.varint()?;
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|_| "share code has invalid text".to_string())
+    }
+
+    fn optional_text(&mut self) -> Result<Option<String>, String> {
+        match self.byte()? {
+            0 => Ok(None),
+            _ => self.text().map(Some),
+        }
+    }
+
+    fn reference(&mut self, table: &IdTable) -> Result<String, String> {
+        match self.varint()? {
+            0 => self.text(),
+            index => table.ids.get(index - 1)
+                .cloned()
+                .ok_or_else(|| format!("share code refers to unknown entry {}", index)),
+        }
+    }
+
+    fn references(&mut self, table: &IdTable) -> Result<Vec<String>, String> {
+        let count = self.varint()?;
+        (0..count).map(|_| self.reference(table)).collect()
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Unpadded base64 with the URL-safe alphabet
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate()
+            .fold(0u32, |group, (i, &byte)| group | (byte as u32) << (16 - i * 8));
+        for i in 0..=chunk.len() {
+            out.push(BASE64_ALPHABET[(group >> (18 - i * 6)) as usize & 0x3F] as char);
+        }
+    }
+    out
+}
+
+fn base64_decode(text: &str) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    for chunk in text.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return Err("share code has the wrong length".to_string());
+        }
+        let mut group = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = BASE64_ALPHABET.iter().position(|&a| a == c)
+                .ok_or_else(|| format!("'{}' can't appear in a share code", c as char))?;
+            group |= (value as u32) << (18 - i * 6);
+        }
+        for i in 0..chunk.len() - 1 {
+            out.push((group >> (16 - i * 8)) as u8);
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::guides::GUIDES;
+
+    #[test]
+    fn test_every_shipped_guide_round_trips() {
+        for guide in GUIDES.values().flatten() {
+            let code = encode_guide(guide);
+            assert!(code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'), "{}", code);
+            assert_eq!(&decode_guide(&code).unwrap(), guide, "{} {}", guide.god_id, guide.role);
+        }
+    }
+
+    #[test]
+    fn test_unknown_ids_and_odd_skill_orders_round_trip() {
+        let mut guide = Guide::new("not_a_god", "Carry")
+            .with_build(vec!["tahuti", "spear_of_the_magus"])
+            .with_skill_order(vec![1, 2, 9])
+            .with_timeline(vec![TimelineEntry { percent: 40, items: Some(vec![]), tip: Some("ünïcode".to_string()) }])
+            .with_strategy("## Plan");
+        guide.title = Some("Odd one".to_string());
+
+        assert_eq!(decode_guide(&encode_guide(&guide)).unwrap(), guide);
+    }
+
+    #[test]
+    fn test_codes_are_compact() {
+        let guide = &GUIDES["agni"][0];
+        let without_strategy = Guide { strategy: None, ..guide.clone() };
+        let json = serde_json::to_string(&without_strategy).unwrap();
+        assert!(encode_guide(&without_strategy).len() * 4 < json.len());
+    }
+
+    #[test]
+    fn test_rejects_bad_codes() {
+        assert!(decode_guide("").is_err());
+        assert!(decode_guide("not base64!").is_err());
+        let code = encode_guide(&GUIDES["agni"][0]);
+        assert!(decode_guide(&code[..code.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_reads_version_1_codes() {
+        let guide = Guide { patch: None, ..GUIDES["agni"][0].clone() };
+        let mut bytes = base64_decode(&encode_guide(&guide)).unwrap();
+        bytes[0] = 1;
+        bytes.pop(); // no patch
+        assert_eq!(decode_guide(&base64_encode(&bytes)).unwrap(), guide);
+    }
+
+    #[test]
+    fn test_tables_cover_the_data() {
+        use crate::data::gods::GODS;
+        use crate::data::items::ITEMS;
+
+        for (name, table, ids) in [("gods", &GOD_TABLE, GODS.keys().collect::<Vec<_>>()), ("items", &ITEM_TABLE, ITEMS.keys().collect())] {
+            assert_eq!(table.positions.len(), table.ids.len(), "duplicate ID in share_ids.json {}", name);
+            let missing: Vec<&&String> = ids.iter().filter(|id| !table.positions.contains_key(id.as_str())).collect();
+            assert!(missing.is_empty(), "append {:?} to the end of share_ids.json {}", missing, name);
+        }
+    }
+
+    #[test]
+    fn test_pinned_code_decodes() {
+        // A code handed out before this test was written; it must keep decoding
+        // to the same guide however items.json and gods.json change
+        assert_eq!(decode_guide("AgICAAKSAiIABADkAR4BASIAAAEDNS4x").unwrap(), Guide {
+            schema_version: GUIDE_SCHEMA_VERSION,
+            title: None,
+            god_id: "agni".to_string(),
+            role: "mid".to_string(),
+            build: vec!["spear_of_the_magus".to_string(), "book_of_thoth".to_string()],
+            relics: Vec::new(),
+            timeline: vec![TimelineEntry { percent: 30, items: Some(vec!["book_of_thoth".to_string()]), tip: None }],
+            skill_order: vec![1, 2, 3, 4],
+            strategy: None,
+            patch: Some("5.1".to_string()),
+        });
+    }
+
+    #[test]
+    fn test_base64_round_trip() {
+        for len in 0..8 {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 77 + 3) as u8).collect();
+            assert_eq!(base64_decode(&base64_encode(&bytes)).unwrap(), bytes);
+        }
+        assert_eq!(base64_encode(b"Man"), "TWFu");
+        assert_eq!(base64_encode(&[0xFB, 0xFF]), "-_8");
+    }
+}
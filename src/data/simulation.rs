@@ -0,0 +1,374 @@
+use crate::data::ability_calc::{god_ability_numbers, AbilityNumbers, Attacker, DamageType};
+use crate::data::gods::{God, GodStats};
+use crate::data::items::ItemStat;
+
+/// Time an ability cast locks the attacker out of other actions
+pub const ABILITY_CAST_TIME: f64 = 0.5;
+
+/// Combos that haven't killed by then are reported as no kill
+pub const MAX_COMBO_TIME: f64 = 60.0;
+
+/// The target of a simulation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Defender {
+    pub health: f64,
+    pub physical_protection: f64,
+    pub magical_protection: f64,
+    pub flat_mitigation: f64,    // subtracted from every hit after protections
+    pub percent_mitigation: f64, // 0-100, applied last
+}
+
+impl Defender {
+    /// A defender with a god's health and protections and no extra mitigation
+    pub fn from_stats(stats: &GodStats) -> Self {
+        Defender {
+            health: stats.health,
+            physical_protection: stats.physical_protection,
+            magical_protection: stats.magical_protection,
+            flat_mitigation: 0.0,
+            percent_mitigation: 0.0,
+        }
+    }
+
+    /// Protection left after the attacker's percent and then flat penetration
+    pub fn effective_protection(&self, attacker: &Attacker) -> f64 {
+        let (protection, flat_pen, percent_pen) = match attacker.damage_type {
+            DamageType::Physical => (
+                self.physical_protection,
+                ItemStat::PhysicalPenetration,
+                ItemStat::PhysicalPenetrationPercent,
+            ),
+            DamageType::Magical => (
+                self.magical_protection,
+                ItemStat::MagicalPenetration,
+                ItemStat::MagicalPenetrationPercent,
+            ),
+        };
+        let flat_pen = attacker.build.get(&flat_pen) as f64;
+        let percent_pen = attacker.build.get(&percent_pen) as f64 / 100.0;
+
+        (protection * (1.0 - percent_pen) - flat_pen).max(0.0)
+    }
+
+    /// Damage a raw hit actually deals to this defender
+    pub fn mitigate(&self, raw: f64, attacker: &Attacker) -> f64 {
+        let after_protection = raw * 100.0 / (100.0 + self.effective_protection(attacker));
+        let after_flat = (after_protection - self.flat_mitigation).max(0.0);
+        after_flat * (1.0 - self.percent_mitigation.clamp(0.0, 100.0) / 100.0)
+    }
+}
+
+/// A single source of damage and how hard it hits the defender
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit {
+    pub name: String,
+    pub slot: Option<usize>, // ability slot 0-3, None for basic attacks
+    pub rank: u8,
+    pub raw: f64,
+    pub effective: f64,
+    pub cooldown: Option<f64>,
+}
+
+impl Hit {
+    fn from_ability(slot: usize, numbers: &AbilityNumbers, attacker: &Attacker, defender: &Defender) -> Self {
+        let raw = if numbers.rank == 0 {
+            0.0
+        } else {
+            numbers.primary_damage().map(|line| line.total()).unwrap_or(0.0)
+        };
+
+        Hit {
+            name: numbers.ability.clone(),
+            slot: Some(slot),
+            rank: numbers.rank,
+            raw,
+            effective: defender.mitigate(raw, attacker),
+            cooldown: numbers.cooldown,
+        }
+    }
+
+    // Needs the attacker's base attack damage and attack speed
+    fn basic_attack(attacker: &Attacker, defender: &Defender) -> Option<Self> {
+        let raw = attacker.basic_attack_power()?;
+        Some(Hit {
+            name: "basic_attack".to_string(),
+            slot: None,
+            rank: 0,
+            raw,
+            effective: defender.mitigate(raw, attacker),
+            cooldown: Some(attacker.stats?.attack_speed.max(0.1).recip()),
+        })
+    }
+}
+
+/// One action of the kill combo
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComboStep {
+    pub time: f64,
+    pub name: String,
+    pub damage: f64,
+    pub remaining_health: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simulation {
+    pub abilities: Vec<Hit>,
+    pub basic_attack: Option<Hit>, // None without base stats for the attacker
+    pub combo: Vec<ComboStep>,
+    pub time_to_kill: Option<f64>,
+}
+
+/// Orderings the combo search tries before settling for the best found so far
+pub const MAX_SEARCH_STEPS: usize = 200_000;
+
+// What the attacker does next: cast a ready ability, basic attack, or wait
+// for the next ability to come off cooldown
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    Cast(usize),
+    Basic,
+    Wait(f64),
+}
+
+struct Search<'a> {
+    abilities: &'a [Hit],
+    basic_attack: Option<&'a Hit>,
+    best_hit: f64,
+    min_step: f64,
+    steps: usize,
+    path: Vec<(f64, Action)>,
+    best: Option<(f64, Vec<(f64, Action)>)>,
+}
+
+impl Search<'_> {
+    fn actions(&self, time: f64, ready_at: &[f64]) -> Vec<Action> {
+        let ready: Vec<Action> = (0..self.abilities.len())
+            .filter(|&i| self.abilities[i].effective > 0.0 && ready_at[i] <= time)
+            .map(Action::Cast)
+            .collect();
+        if !ready.is_empty() {
+            return ready;
+        }
+
+        let mut actions = Vec::new();
+        if self.basic_attack.is_some_and(|basic| basic.effective > 0.0) {
+            actions.push(Action::Basic);
+        }
+        let next_ready = (0..self.abilities.len())
+            .filter(|&i| self.abilities[i].effective > 0.0)
+            .map(|i| ready_at[i])
+            .fold(f64::INFINITY, f64::min);
+        if next_ready.is_finite() {
+            actions.push(Action::Wait(next_ready));
+        }
+        actions
+    }
+
+    fn explore(&mut self, time: f64, health: f64, ready_at: &mut [f64]) {
+        if self.steps >= MAX_SEARCH_STEPS || time > MAX_COMBO_TIME {
+            return;
+        }
+        self.steps += 1;
+
+        // Every hit still needed takes at least `min_step` after the first
+        let hits_needed = (health / self.best_hit).ceil();
+        let earliest_kill = time + (hits_needed - 1.0).max(0.0) * self.min_step;
+        if self.best.as_ref().is_some_and(|(best, _)| earliest_kill >= *best) {
+            return;
+        }
+
+        for action in self.actions(time, ready_at) {
+            let (damage, next_time) = match action {
+                Action::Cast(i) => (self.abilities[i].effective, time + ABILITY_CAST_TIME),
+                Action::Basic => {
+                    let basic = self.basic_attack.expect("basic attacks are offered only when known");
+                    (basic.effective, time + basic.cooldown.unwrap_or(1.0))
+                }
+                Action::Wait(until) => (0.0, until),
+            };
+
+            self.path.push((time, action));
+            if health - damage <= 0.0 {
+                if self.best.as_ref().is_none_or(|(best, _)| time < *best) {
+                    self.best = Some((time, self.path.clone()));
+                }
+            } else if let Action::Cast(i) = action {
+                let previous = ready_at[i];
+                ready_at[i] = time + self.abilities[i].cooldown.unwrap_or(0.0).max(ABILITY_CAST_TIME);
+                self.explore(next_time, health - damage, ready_at);
+                ready_at[i] = previous;
+            } else {
+                self.explore(next_time, health - damage, ready_at);
+            }
+            self.path.pop();
+        }
+    }
+}
+
+/// Fastest order of casts and basic attacks that kills `health`, as the actions
+/// taken and the time of the killing blow. Searches every ordering of ready
+/// abilities, and basic attacking or waiting while all are on cooldown, up to
+/// `MAX_SEARCH_STEPS`.
+pub fn fastest_combo(abilities: &[Hit], basic_attack: Option<&Hit>, health: f64) -> (Vec<ComboStep>, Option<f64>) {
+    let usable = abilities.iter().chain(basic_attack).filter(|hit| hit.effective > 0.0);
+    let best_hit = usable.clone().map(|hit| hit.effective).fold(0.0, f64::max);
+    if best_hit <= 0.0 {
+        return (Vec::new(), None);
+    }
+    let min_step = usable.map(|hit| match hit.slot {
+        Some(_) => ABILITY_CAST_TIME,
+        None => hit.cooldown.unwrap_or(1.0),
+    }).fold(f64::INFINITY, f64::min);
+
+    let mut search = Search { abilities, basic_attack, best_hit, min_step, steps: 0, path: Vec::new(), best: None };
+    search.explore(0.0, health, &mut vec![0.0; abilities.len()]);
+    let Some((time_to_kill, path)) = search.best else {
+        return (Vec::new(), None);
+    };
+
+    let mut remaining = health;
+    let combo = path.into_iter()
+        .filter_map(|(time, action)| {
+            let hit = match action {
+                Action::Cast(i) => &abilities[i],
+                Action::Basic => basic_attack?,
+                Action::Wait(_) => return None,
+            };
+            remaining -= hit.effective;
+            Some(ComboStep { time, name: hit.name.clone(), damage: hit.effective, remaining_health: remaining.max(0.0) })
+        })
+        .collect();
+    (combo, Some(time_to_kill))
+}
+
+/// Simulate an attacker against a defender, with the fastest combo to kill it
+pub fn simulate(god: &God, skill_order: &[u8], attacker: &Attacker, defender: &Defender) -> Simulation {
+    let abilities: Vec<Hit> = god_ability_numbers(god, skill_order, attacker)
+        .iter()
+        .enumerate()
+        .map(|(slot, numbers)| Hit::from_ability(slot, numbers, attacker, defender))
+        .collect();
+    let basic_attack = Hit::basic_attack(attacker, defender);
+    let (combo, time_to_kill) = fastest_combo(&abilities, basic_attack.as_ref(), defender.health);
+
+    Simulation { abilities, basic_attack, combo, time_to_kill }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::build_stats::BuildStats;
+    use crate::data::gods::{test_god, GODS};
+
+    const AGNI_ORDER: [u8; 20] = [3, 2, 2, 1, 4, 2, 2, 3, 4, 2, 3, 3, 4, 3, 1, 1, 4, 1, 1, 4];
+
+    fn target(protection: f64) -> Defender {
+        Defender {
+            health: 2000.0,
+            physical_protection: protection,
+            magical_protection: protection,
+            flat_mitigation: 0.0,
+            percent_mitigation: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_protection_mitigation() {
+        let agni = GODS.get("agni").unwrap();
+        let attacker = Attacker::new(agni, 20, BuildStats::default());
+
+        assert_eq!(target(0.0).mitigate(300.0, &attacker), 300.0);
+        assert_eq!(target(100.0).mitigate(300.0, &attacker), 150.0);
+
+        let mut defender = target(100.0);
+        defender.flat_mitigation = 50.0;
+        defender.percent_mitigation = 20.0;
+        assert_eq!(defender.mitigate(300.0, &attacker), 80.0);
+    }
+
+    #[test]
+    fn test_penetration_lowers_protection() {
+        let agni = GODS.get("agni").unwrap();
+        let pen_items: Vec<_> = crate::data::items::ITEMS.iter()
+            .filter(|(_, item)| item.stats.contains_key(&ItemStat::MagicalPenetration))
+            .map(|(name, _)| name.clone())
+            .take(1)
+            .collect();
+        let build = BuildStats::from_items(&pen_items);
+        let pen = build.get(&ItemStat::MagicalPenetration) as f64;
+        let attacker = Attacker::new(agni, 20, build);
+
+        assert!(pen > 0.0);
+        assert_eq!(target(100.0).effective_protection(&attacker), 100.0 - pen);
+        assert_eq!(target(5.0).effective_protection(&attacker), (5.0 - pen).max(0.0));
+    }
+
+    #[test]
+    fn test_combo_kills_and_respects_cooldowns() {
+        let agni = test_god("agni");
+        let attacker = Attacker::new(&agni, 20, BuildStats::from_items(["spear_of_the_magus"]));
+        let sim = simulate(&agni, &AGNI_ORDER, &attacker, &target(60.0));
+
+        let ttk = sim.time_to_kill.expect("agni should kill a 2000 health target");
+        assert_eq!(sim.combo.last().unwrap().time, ttk);
+        assert_eq!(sim.combo.last().unwrap().remaining_health, 0.0);
+
+        // No ability is cast again before its cooldown is up
+        for hit in sim.abilities.iter().filter(|h| h.effective > 0.0) {
+            let casts: Vec<f64> = sim.combo.iter().filter(|s| s.name == hit.name).map(|s| s.time).collect();
+            for pair in casts.windows(2) {
+                assert!(pair[1] - pair[0] >= hit.cooldown.unwrap_or(0.0) - 1e-9);
+            }
+        }
+    }
+
+    fn hit(name: &str, slot: Option<usize>, effective: f64, cooldown: f64) -> Hit {
+        Hit { name: name.to_string(), slot, rank: 1, raw: effective, effective, cooldown: Some(cooldown) }
+    }
+
+    #[test]
+    fn test_finds_faster_combo_than_greedy() {
+        // Opening with the biggest hit leaves a slow basic attack to finish;
+        // opening with the short cooldown lets it come back for the kill
+        let abilities = [hit("big", Some(0), 200.0, 10.0), hit("quick", Some(1), 120.0, 1.0)];
+        let basic = hit("basic_attack", None, 5.0, 2.0);
+
+        let (combo, ttk) = fastest_combo(&abilities, Some(&basic), 330.0);
+        assert_eq!(ttk, Some(1.0));
+        let names: Vec<&str> = combo.iter().map(|step| step.name.as_str()).collect();
+        assert_eq!(names, vec!["quick", "big", "quick"]);
+        assert_eq!(combo.last().unwrap().remaining_health, 0.0);
+    }
+
+    #[test]
+    fn test_waits_for_cooldowns_without_basic_attacks() {
+        let abilities = [hit("only", Some(0), 100.0, 4.0)];
+        let (combo, ttk) = fastest_combo(&abilities, None, 150.0);
+        assert_eq!(ttk, Some(4.0));
+        assert_eq!(combo.len(), 2);
+
+        assert_eq!(fastest_combo(&[], None, 150.0), (Vec::new(), None));
+    }
+
+    #[test]
+    fn test_unleveled_abilities_are_skipped() {
+        let agni = test_god("agni");
+        let attacker = Attacker::new(&agni, 1, BuildStats::default());
+        let sim = simulate(&agni, &AGNI_ORDER, &attacker, &target(0.0));
+
+        let leveled: Vec<_> = sim.abilities.iter().filter(|h| h.rank > 0).collect();
+        assert_eq!(leveled.len(), 1);
+        assert!(sim.combo.iter().all(|s| s.name == leveled[0].name || s.name == "basic_attack"));
+    }
+
+    #[test]
+    fn test_no_basic_attacks_without_base_stats() {
+        let agni = GODS.get("agni").unwrap();
+        let attacker = Attacker::new(agni, 20, BuildStats::from_items(["spear_of_the_magus"]));
+        let sim = simulate(agni, &AGNI_ORDER, &attacker, &target(60.0));
+
+        assert_eq!(sim.basic_attack, None);
+        assert!(!sim.combo.is_empty());
+        assert!(sim.combo.iter().all(|s| s.name != "basic_attack"));
+    }
+}
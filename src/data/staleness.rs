@@ -0,0 +1,218 @@
+use crate::data::aliases::{resolve_ability_alias, resolve_item_alias, text_mentions};
+use crate::data::guide_sources::GuideEntry;
+use crate::data::guides::Guide;
+use crate::data::patch_diff::{diff_to_current, PatchDiff};
+use crate::data::patches::{find_patch, older_patch};
+
+/// Part of a guide that refers to an item or ability
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reference {
+    Build,
+    Relics,
+    Timeline,
+    Skills, // the god's abilities, levelled by the skill order
+    Strategy, // named in the strategy text, the way `TextWithIcons` links it
+}
+
+impl Reference {
+    pub fn label(self) -> &'static str {
+        match self {
+            Reference::Build => "build",
+            Reference::Relics => "relics",
+            Reference::Timeline => "timeline",
+            Reference::Skills => "skill order",
+            Reference::Strategy => "strategy",
+        }
+    }
+}
+
+/// An item or ability a guide uses that a patch changed or removed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Affected {
+    pub id: String,
+    pub removed: bool,
+    pub references: Vec<Reference>,
+}
+
+/// What a patch did to the things a guide uses, in the order the guide uses them
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GuideImpact {
+    pub items: Vec<Affected>,
+    pub abilities: Vec<Affected>,
+}
+
+impl GuideImpact {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty() && self.abilities.is_empty()
+    }
+}
+
+/// A guide that needs another look after a patch
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaleGuide {
+    pub entry: GuideEntry,
+    pub impact: GuideImpact,
+}
+
+// Canonical IDs with every part of the guide that refers to them
+type References = Vec<(String, Vec<Reference>)>;
+
+fn add(references: &mut References, id: String, reference: Reference) {
+    match references.iter_mut().find(|(known, _)| *known == id) {
+        Some((_, found)) if !found.contains(&reference) => found.push(reference),
+        Some(_) => {}
+        None => references.push((id, vec![reference])),
+    }
+}
+
+fn guide_references(guide: &Guide, diff: &PatchDiff) -> (References, References) {
+    let mut items = References::new();
+    let mut abilities = References::new();
+
+    for item in &guide.build {
+        add(&mut items, resolve_item_alias(item), Reference::Build);
+    }
+    for item in &guide.relics {
+        add(&mut items, resolve_item_alias(item), Reference::Relics);
+    }
+    for item in guide.timeline.iter().flat_map(|entry| entry.items.iter().flatten()) {
+        add(&mut items, resolve_item_alias(item), Reference::Timeline);
+    }
+
+    // The god's kit on either side of the patch
+    let kits = [&diff.from, &diff.to].into_iter()
+        .filter_map(|patch| find_patch(patch)?.gods.get(&guide.god_id));
+    for ability in kits.flat_map(|god| &god.abilities) {
+        add(&mut abilities, resolve_ability_alias(ability), Reference::Skills);
+    }
+
+    if let Some(strategy) = &guide.strategy {
+        let (mentioned_items, mentioned_abilities) = text_mentions(strategy);
+        for item in mentioned_items {
+            add(&mut items, item, Reference::Strategy);
+        }
+        for ability in mentioned_abilities {
+            add(&mut abilities, ability, Reference::Strategy);
+        }
+    }
+
+    (items, abilities)
+}
+
+/// Items and abilities the guide uses that the patch changed or removed
+pub fn guide_impact(guide: &Guide, diff: &PatchDiff) -> GuideImpact {
+    let (items, abilities) = guide_references(guide, diff);
+
+    let affected = |references: References, removed: &[String], changed: &dyn Fn(&str) -> bool| -> Vec<Affected> {
+        references.into_iter()
+            .filter_map(|(id, references)| {
+                let removed = removed.contains(&id);
+                (removed || changed(&id)).then_some(Affected { id, removed, references })
+            })
+            .collect()
+    };
+
+    GuideImpact {
+        items: affected(items, &diff.removed_items, &|id| diff.changed_items.iter().any(|item| item.id == id)),
+        abilities: affected(abilities, &diff.removed_abilities, &|id| diff.changed_abilities.iter().any(|ability| ability.id == id)),
+    }
+}
+
+/// Guides the patch affects, in the order given
+pub fn staleness_report(entries: &[GuideEntry], diff: &PatchDiff) -> Vec<StaleGuide> {
+    entries.iter()
+        .map(|entry| StaleGuide { entry: entry.clone(), impact: guide_impact(&entry.guide, diff) })
+        .filter(|stale| !stale.impact.is_empty())
+        .collect()
+}
+
+/// For a guide written on an older bundled patch: that patch, and what changed
+/// since in the things the guide uses
+pub fn impact_since_written(guide: &Guide) -> Option<(&'static str, GuideImpact)> {
+    let patch = older_patch(guide)?;
+    let diff = diff_to_current(patch.id)?;
+    Some((patch.id, guide_impact(guide, diff)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::guide_sources::{curated_id, merge_guides};
+    use crate::data::guides::{TimelineEntry, GUIDES};
+    use crate::data::patch_diff::{diff_patches, AbilityChange};
+    use crate::data::patches::current_patch;
+
+    fn diff() -> PatchDiff {
+        PatchDiff {
+            from: "5.0".to_string(),
+            to: current_patch().id.to_string(),
+            removed_items: vec!["rod_of_tahuti".to_string()],
+            changed_abilities: vec![AbilityChange {
+                id: "flame_wave".to_string(),
+                name: None,
+                description: true,
+                details: Vec::new(),
+            }],
+            ..PatchDiff::default()
+        }
+    }
+
+    #[test]
+    fn test_guide_impact() {
+        let mut guide = Guide::new("agni", "mid");
+        guide.build = vec!["spear_of_the_magus".to_string(), "tahuti".to_string()];
+        guide.timeline = vec![TimelineEntry { percent: 50, items: Some(vec!["rod_of_tahuti".to_string()]), tip: None }];
+        guide.strategy = Some("Rush **Tahuti**, then fwave everything.".to_string());
+
+        let impact = guide_impact(&guide, &diff());
+        assert_eq!(impact.items, vec![Affected {
+            id: "rod_of_tahuti".to_string(),
+            removed: true,
+            references: vec![Reference::Build, Reference::Timeline, Reference::Strategy],
+        }]);
+        assert_eq!(impact.abilities, vec![Affected {
+            id: "flame_wave".to_string(),
+            removed: false,
+            references: vec![Reference::Skills, Reference::Strategy],
+        }]);
+
+        // Zeus doesn't have Flame Wave, and doesn't mention it
+        let zeus = Guide::new("zeus", "mid");
+        assert!(guide_impact(&zeus, &diff()).is_empty());
+    }
+
+    #[test]
+    fn test_staleness_report() {
+        let entries: Vec<GuideEntry> = merge_guides(&GUIDES, &[]).into_values().flatten().collect();
+        let report = staleness_report(&entries, &diff());
+        assert!(report.iter().any(|stale| stale.entry.id == curated_id("agni", "mid")));
+        assert!(report.iter().all(|stale| !stale.impact.is_empty()));
+
+        // Curated guides don't say, so they count as written on the oldest bundled patch
+        assert!(GUIDES.values().flatten().all(|guide| impact_since_written(guide).is_some_and(|(patch, _)| patch == "5.0")));
+        // but only the ones using something that changed get the notice
+        let (affected, unaffected): (Vec<&Guide>, Vec<&Guide>) = GUIDES.values().flatten()
+            .partition(|guide| impact_since_written(guide).is_some_and(|(_, impact)| !impact.is_empty()));
+        assert!(!affected.is_empty() && !unaffected.is_empty());
+
+        // `cargo test staleness -- --nocapture` lists the guides 5.0 -> 5.1 affects
+        let real = diff_patches(find_patch("5.0").unwrap(), current_patch());
+        for stale in staleness_report(&entries, &real) {
+            let items: Vec<&str> = stale.impact.items.iter().map(|affected| affected.id.as_str()).collect();
+            println!("{} {} ({}): {}", stale.entry.guide.god_id, stale.entry.guide.role, stale.entry.id, items.join(", "));
+        }
+    }
+
+    #[test]
+    fn test_impact_since_written() {
+        let mut guide = Guide::new("agni", "mid");
+        guide.build = vec!["protectors_mask".to_string(), "rod_of_tahuti".to_string()];
+        assert!(impact_since_written(&guide).is_none());
+
+        guide.patch = Some("5.0".to_string());
+        let (patch, impact) = impact_since_written(&guide).unwrap();
+        assert_eq!(patch, "5.0");
+        let items: Vec<&str> = impact.items.iter().map(|affected| affected.id.as_str()).collect();
+        assert_eq!(items, vec!["protectors_mask"]);
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::HashSet;
+use std::fmt;
+use crate::data::aliases::resolve_item_alias;
+use crate::data::gods::{GODS, MAX_LEVEL};
+use crate::data::guides::Guide;
+use crate::data::items::{ItemTag, ITEMS};
+
+/// Most items a final build can hold
+pub const MAX_BUILD_ITEMS: usize = 6;
+
+/// Most relics a build can hold
+pub const MAX_RELICS: usize = 2;
+
+/// Highest rank any ability can reach
+pub const MAX_SKILL_RANK: u8 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// One problem found in a guide
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub path: String, // field path, e.g. "build[2]" or "timeline[1].percent"
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn error(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Diagnostic { severity: Severity::Error, path: path.into(), message: message.into() }
+    }
+
+    fn warning(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Diagnostic { severity: Severity::Warning, path: path.into(), message: message.into() }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Highest rank a skill (1-4, 4 being the ultimate) may have at a level
+pub fn max_skill_rank(skill: u8, level: u8) -> u8 {
+    if skill == 4 {
+        match level {
+            1..=4 => 0,   // Can't level ultimate until level 5
+            5..=8 => 1,   // Max 1 rank until level 9
+            9..=12 => 2,  // Max 2 ranks until level 13
+            13..=16 => 3, // Max 3 ranks until level 17
+            17..=19 => 4, // Max 4 ranks until level 20
+            20 => 5,      // Max 5 ranks at level 20
+            _ => 0,
+        }
+    } else {
+        match level {
+            1..=2 => 1,   // Can only have 1 point in abilities at levels 1-2
+            3..=4 => 2,   // Can have 2 points in abilities at levels 3-4
+            5..=8 => 3,   // Can have 3 points in abilities at levels 5-8
+            9..=14 => 4,  // Can have 4 points in abilities at levels 9-14
+            15..=20 => 5, // Can have 5 points in abilities at levels 15+
+            _ => 0,
+        }
+    }
+}
+
+/// Whether one more point can go into `skill` (1-4) at `level` (1-based),
+/// given the points already spent in `current_order`
+pub fn can_level_skill(skill: u8, level: u8, current_order: &[u8]) -> bool {
+    if !(1..=4).contains(&skill) {
+        return false;
+    }
+
+    let spent = current_order.iter().filter(|&&s| (1..=4).contains(&s)).count();
+    // Can't allocate more points than levels
+    if spent >= level as usize {
+        return false;
+    }
+
+    let current_rank = current_order.iter().filter(|&&s| s == skill).count() as u8;
+    current_rank < MAX_SKILL_RANK && current_rank < max_skill_rank(skill, level)
+}
+
+/// Check a skill order point by point against the rank caps
+pub fn validate_skill_order(skill_order: &[u8]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    if skill_order.len() > MAX_LEVEL as usize {
+        diagnostics.push(Diagnostic::error(
+            "skill_order",
+            format!("{} points but gods only reach level {}", skill_order.len(), MAX_LEVEL),
+        ));
+    }
+
+    let mut ranks = [0u8; 4];
+    for (i, &skill) in skill_order.iter().enumerate().take(MAX_LEVEL as usize) {
+        let path = format!("skill_order[{}]", i);
+        let level = i as u8 + 1;
+
+        if !(1..=4).contains(&skill) {
+            diagnostics.push(Diagnostic::error(path, format!("{} is not an ability (expected 1-4)", skill)));
+            continue;
+        }
+
+        let rank = &mut ranks[skill as usize - 1];
+        *rank += 1;
+        let cap = max_skill_rank(skill, level).min(MAX_SKILL_RANK);
+        if *rank > cap {
+            let message = if cap == 0 {
+                format!("ability {} can't be leveled before level 5", skill)
+            } else {
+                format!("ability {} reaches rank {} at level {} (max {})", skill, rank, level, cap)
+            };
+            // The creator won't let this happen; imported and curated guides
+            // still load so their authors can fix them
+            diagnostics.push(Diagnostic::warning(path, message));
+        }
+    }
+
+    diagnostics
+}
+
+/// Check a guide's god, items, relics, timeline and skill order
+pub fn validate_guide(guide: &Guide) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    if guide.god_id.is_empty() {
+        diagnostics.push(Diagnostic::error("god_id", "no god selected"));
+    } else if !GODS.contains_key(&guide.god_id) {
+        diagnostics.push(Diagnostic::error("god_id", format!("unknown god '{}'", guide.god_id)));
+    }
+
+    if guide.role.is_empty() {
+        diagnostics.push(Diagnostic::warning("role", "no role selected"));
+    }
+
+    // Build
+    if guide.build.len() > MAX_BUILD_ITEMS {
+        diagnostics.push(Diagnostic::error(
+            "build",
+            format!("{} items but a build holds at most {}", guide.build.len(), MAX_BUILD_ITEMS),
+        ));
+    }
+    let mut seen = HashSet::new();
+    for (i, name) in guide.build.iter().enumerate() {
+        let path = format!("build[{}]", i);
+        let resolved = resolve_item_alias(name);
+        match ITEMS.get(&resolved) {
+            None => diagnostics.push(Diagnostic::error(path.clone(), format!("unknown item '{}'", name))),
+            Some(item) if item.tags.contains(&ItemTag::Relic) => {
+                diagnostics.push(Diagnostic::warning(path.clone(), format!("'{}' is a relic, not a build item", name)));
+            }
+            Some(_) => {}
+        }
+        if !seen.insert(resolved) {
+            diagnostics.push(Diagnostic::error(path, format!("'{}' is already in the build", name)));
+        }
+    }
+
+    // Relics
+    if guide.relics.len() > MAX_RELICS {
+        diagnostics.push(Diagnostic::error(
+            "relics",
+            format!("{} relics but a build holds at most {}", guide.relics.len(), MAX_RELICS),
+        ));
+    }
+    let mut seen = HashSet::new();
+    for (i, name) in guide.relics.iter().enumerate() {
+        let path = format!("relics[{}]", i);
+        let resolved = resolve_item_alias(name);
+        match ITEMS.get(&resolved) {
+            None => diagnostics.push(Diagnostic::error(path.clone(), format!("unknown item '{}'", name))),
+            Some(item) if !item.tags.contains(&ItemTag::Relic) => {
+                diagnostics.push(Diagnostic::warning(path.clone(), format!("'{}' is not a relic", name)));
+            }
+            Some(_) => {}
+        }
+        if !seen.insert(resolved) {
+            diagnostics.push(Diagnostic::error(path, format!("'{}' is already in the relics", name)));
+        }
+    }
+
+    // Timeline
+    let mut previous_percent = None;
+    for (i, entry) in guide.timeline.iter().enumerate() {
+        if entry.percent > 100 {
+            diagnostics.push(Diagnostic::error(
+                format!("timeline[{}].percent", i),
+                format!("{}% is past the end of the game", entry.percent),
+            ));
+        }
+        if let Some(previous) = previous_percent {
+            if entry.percent < previous {
+                diagnostics.push(Diagnostic::warning(
+                    format!("timeline[{}].percent", i),
+                    format!("{}% comes after {}%", entry.percent, previous),
+                ));
+            }
+        }
+        previous_percent = Some(entry.percent);
+
+        for (j, name) in entry.items.iter().flatten().enumerate() {
+            if !ITEMS.contains_key(&resolve_item_alias(name)) {
+                diagnostics.push(Diagnostic::error(
+                    format!("timeline[{}].items[{}]", i, j),
+                    format!("unknown item '{}'", name),
+                ));
+            }
+        }
+    }
+
+    diagnostics.extend(validate_skill_order(&guide.skill_order));
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::guides::{TimelineEntry, GUIDES};
+
+    fn paths(diagnostics: &[Diagnostic]) -> Vec<&str> {
+        diagnostics.iter().map(|d| d.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_shipped_guides_have_no_errors() {
+        for guide in GUIDES.values().flatten() {
+            let errors: Vec<String> = validate_guide(guide).iter()
+                .filter(|d| d.is_error())
+                .map(|d| d.to_string())
+                .collect();
+            assert!(errors.is_empty(), "{} {}: {:?}", guide.god_id, guide.role, errors);
+        }
+    }
+
+    #[test]
+    fn test_build_and_relic_problems() {
+        let guide = Guide::new("agni", "mid")
+            .with_build(vec!["not_an_item", "spear_of_the_magus", "spear_of_the_magus", "katana", "the_crusher", "rod_of_tahuti", "obsidian_shard"])
+            .with_relics(vec!["katana"]);
+        let diagnostics = validate_guide(&guide);
+
+        assert_eq!(paths(&diagnostics), vec!["build", "build[0]", "build[2]", "relics[0]"]);
+        assert!(diagnostics[..3].iter().all(Diagnostic::is_error));
+        assert_eq!(diagnostics[3].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_timeline_problems() {
+        let entry = |percent| TimelineEntry { percent, items: None, tip: None };
+        let guide = Guide::new("agni", "mid").with_timeline(vec![entry(20), entry(10), entry(120)]);
+        let diagnostics = validate_guide(&guide);
+
+        assert_eq!(paths(&diagnostics), vec!["timeline[1].percent", "timeline[2].percent"]);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[1].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_skill_order_rank_caps() {
+        assert!(validate_skill_order(&[1, 2, 1, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 2, 1, 3, 4, 2, 3, 4]).is_empty());
+        // Rank 4 at level 7 is over the 5-8 cap of 3
+        assert_eq!(paths(&validate_skill_order(&[3, 2, 2, 1, 4, 2, 2])), vec!["skill_order[6]"]);
+
+        // Rank 2 of ability 1 at level 2, ultimate at level 3, and a bogus slot
+        let diagnostics = validate_skill_order(&[1, 1, 4, 7]);
+        assert_eq!(paths(&diagnostics), vec!["skill_order[1]", "skill_order[2]", "skill_order[3]"]);
+        let severities: Vec<Severity> = diagnostics.iter().map(|d| d.severity).collect();
+        assert_eq!(severities, vec![Severity::Warning, Severity::Warning, Severity::Error]);
+    }
+
+    #[test]
+    fn test_rank_caps_at_level_edges() {
+        let caps: Vec<(u8, u8)> = [2, 3, 4, 5, 8, 9, 14, 15, 20].iter().map(|&level| (level, max_skill_rank(1, level))).collect();
+        assert_eq!(caps, vec![(2, 1), (3, 2), (4, 2), (5, 3), (8, 3), (9, 4), (14, 4), (15, 5), (20, 5)]);
+
+        let ult: Vec<u8> = [4, 5, 8, 9, 12, 13, 16, 17, 19, 20].iter().map(|&level| max_skill_rank(4, level)).collect();
+        assert_eq!(ult, vec![0, 1, 1, 2, 2, 3, 3, 4, 4, 5]);
+    }
+
+    #[test]
+    fn test_can_level_skill() {
+        assert!(can_level_skill(1, 1, &[]));
+        assert!(!can_level_skill(4, 1, &[]));
+        assert!(!can_level_skill(1, 2, &[1]));
+        assert!(can_level_skill(4, 5, &[1, 2, 3, 1]));
+        assert!(!can_level_skill(1, 1, &[2]));
+    }
+}
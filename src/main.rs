@@ -7,17 +7,42 @@ mod data;
 mod routes;
 mod utils;
 
-use routes::{cheatsheet::Cheatsheet, home::Home, guide_creator::GuideCreator};
-use components::ScrollToTop;
+use routes::{cheatsheet::Cheatsheet, home::{Home, Browse, GodPage, GodRolePage}, guide_creator::{GuideCreator, GuideEdit, GuideFork}, simulate::Simulate, my_guides::{MyGuides, SavedGuideView}, shared_guide::SharedGuide, compare::Compare, items::Items, patch_notes::PatchNotes};
+use components::{DataErrorScreen, ScrollToTop};
 
-#[derive(Routable, Clone)]
+#[derive(Routable, Clone, PartialEq, Debug)]
 enum Route {
-    #[route("/")]
-    Home,
-    #[route("/cheatsheet")]
-    Cheatsheet,
+    // The home page stays mounted while the god/role in the URL changes
+    #[layout(Home)]
+        #[route("/?:class&:role")]
+        Browse { class: String, role: String },
+        #[route("/god/:god?:class&:role")]
+        GodPage { god: String, class: String, role: String },
+        #[route("/god/:god/:build_role?:class&:role")]
+        GodRolePage { god: String, build_role: String, class: String, role: String },
+    #[end_layout]
+    #[route("/cheatsheet?:class&:role")]
+    Cheatsheet { class: String, role: String },
     #[route("/guide/create")]
     GuideCreator,
+    #[route("/guide/edit/:id")]
+    GuideEdit { id: String },
+    #[route("/guide/fork/:code")]
+    GuideFork { code: String },
+    #[route("/guides")]
+    MyGuides,
+    #[route("/guides/:id")]
+    SavedGuideView { id: String },
+    #[route("/g/:code")]
+    SharedGuide { code: String },
+    #[route("/compare?:left&:right")]
+    Compare { left: String, right: String },
+    #[route("/items?:item")]
+    Items { item: String },
+    #[route("/patch-notes?:from&:to")]
+    PatchNotes { from: String, to: String },
+    #[route("/simulate")]
+    Simulate,
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -79,6 +104,15 @@ impl TooltipPos {
 
 fn main() {
     wasm_logger::init(wasm_logger::Config::new(Level::Info));
+
+    // Report broken bundled data; `app` shows an error screen if a file didn't load
+    for error in &data::registry::DATA.load_errors {
+        log::error!("{}", error);
+    }
+    if !data::registry::DATA.reference_errors.is_empty() {
+        log::warn!("{}", data::integrity::report(&data::registry::DATA.reference_errors));
+    }
+
     dioxus::launch(app);
 }
 
@@ -105,6 +139,13 @@ fn app() -> Element {
         load_from_storage("selected_build").map(|s| s.to_string())
     )));
 
+    // Read saved guides once, upgrading ones saved by older versions of the creator
+    use_context_provider(|| {
+        let saved = data::saved_guides::load_saved_guides();
+        log::info!("{} saved guides in local storage", saved.len());
+        Signal::new(data::saved_guides::SavedGuides(saved))
+    });
+
     // Watch for changes and save to localStorage
     use_effect(move || {
         match &filtered_class.read().0 {
@@ -163,8 +204,12 @@ fn app() -> Element {
             document::Link { rel: "icon", href: asset!("/assets/icons/favicon.ico") }
             document::Link { rel: "stylesheet", href: asset!("/assets/app.css") }
 
-            Router::<Route> {}
-            ScrollToTop {}
+            if data::registry::DATA.is_broken() {
+                DataErrorScreen {}
+            } else {
+                Router::<Route> {}
+                ScrollToTop {}
+            }
         }
     }
 } 
\ No newline at end of file
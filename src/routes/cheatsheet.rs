@@ -1,18 +1,23 @@
 use dioxus::prelude::*;
 use crate::components::{Tooltip, ClassFilters, RoleFilters, Header, Item};
 use crate::data::gods::GODS;
-use crate::data::guides::GUIDES;
-use crate::{FilteredClass, FilteredRole, SelectedRole, SelectedGod};
-use crate::data::aliases::resolve_role_alias;
+use crate::data::search::fuzzy_matches;
+use crate::data::guide_sources::{all_guides, has_role, distinct_roles, GuideEntry};
+use crate::data::saved_guides::SavedGuides;
+use crate::{FilteredClass, FilteredRole, SelectedRole, SelectedGod, SelectedBuild};
+use crate::routes::links::{use_link_sync, LinkScope, LinkState};
 use web_sys::window;
 use wasm_bindgen::{JsCast, closure::Closure};
 
 #[component]
-pub fn Cheatsheet() -> Element {
+pub fn Cheatsheet(class: String, role: String) -> Element {
+    use_link_sync(LinkState::from_route(&Route::Cheatsheet { class, role }), LinkScope::Cheatsheet);
+
     // Use shared filter state
     let filtered_role = use_context::<Signal<FilteredRole>>();
     let selected_role = use_context::<Signal<SelectedRole>>();
     let class = use_context::<Signal<FilteredClass>>();
+    let saved_guides = use_context::<Signal<SavedGuides>>();
     let mut search = use_signal(|| String::new());
     let search_input_id = "cheatsheet-search-input";
     
@@ -69,21 +74,16 @@ pub fn Cheatsheet() -> Element {
     let filter_role = filtered_role.read();
     let sel_role = selected_role.read();
     let selected_class = class.read();
+    let guides = all_guides(&saved_guides.read().0);
     
     let mut filtered_gods: Vec<_> = GODS.iter()
         .filter(|(name, god)| {
-            let name_matches = name.contains(&search_term);
+            let name_matches = fuzzy_matches(&search_term, &[name, &god.display_name]);
             
             // Check if god has builds for the filtered role
             let role_matches = filter_role.0.as_ref().map_or(true, |r| {
-                let resolved_role = resolve_role_alias(r);
-                
-                // Check if god has any guides for this role
-                if let Some(guides) = GUIDES.get(*name) {
-                    guides.iter().any(|guide| guide.role == resolved_role)
-                } else {
-                    false
-                }
+                // Check if god has any guides (curated or saved) for this role
+                guides.get(*name).is_some_and(|entries| has_role(entries, r))
             });
             
             let class_matches = selected_class.0.as_ref().map_or(true, |c| c.to_lowercase() == god.class.to_lowercase());
@@ -255,6 +255,7 @@ pub fn Cheatsheet() -> Element {
                                 CheatsheetCard {
                                     god_name: (*name).clone(),
                                     god_info: (*god).clone(),
+                                    guides: guides.get(*name).cloned().unwrap_or_default(),
                                     filtered_role: filter_role.0.clone(),
                                     selected_role: sel_role.0.clone(),
                                     filtered_class: selected_class.0.clone(),
@@ -274,28 +275,24 @@ pub fn Cheatsheet() -> Element {
 fn CheatsheetCard(
     god_name: String, 
     god_info: crate::data::gods::God,
+    guides: Vec<GuideEntry>,
     filtered_role: Option<String>,
     selected_role: Option<String>,
     filtered_class: Option<String>,
 ) -> Element {
     let mut selected_god = use_context::<Signal<SelectedGod>>();
     let mut selected_role_signal = use_context::<Signal<SelectedRole>>();
+    let mut selected_build = use_context::<Signal<SelectedBuild>>();
     let mut is_hovered = use_signal(|| false);
     
-    // Get all guides for the god (no filtering)
-    let all_guides = GUIDES.get(&god_name)
-        .map(|guides| guides.iter().collect::<Vec<_>>())
-        .unwrap_or_default();
+    // All guides for the god, curated and saved (no filtering)
+    let all_guides = guides;
     
     let has_builds_for_filters = !all_guides.is_empty();
     
     // Get unique roles from all guides for this god
     let mut unique_roles: Vec<String> = if has_builds_for_filters {
-        let mut roles = std::collections::HashSet::new();
-        for guide in &all_guides {
-            roles.insert(guide.role.clone());
-        }
-        roles.into_iter().collect()
+        distinct_roles(&all_guides)
     } else {
         god_info.roles.clone()
     };
@@ -396,18 +393,27 @@ fn CheatsheetCard(
                 div {
                     style: "display: flex; flex-direction: column; gap: 4px;",
                     
-                    for (_idx, guide_data) in all_guides.iter().enumerate() {
+                    for entry in all_guides.iter() {
                         // Clickable build container
                         div {
+                            key: "{entry.id}",
                             class: "build-container",
                             style: "padding: 4px; border-radius: 4px; cursor: pointer; transition: all 0.2s ease;",
                             onclick: {
                                 let god_name = god_name.clone();
-                                let role = guide_data.role.clone();
+                                let role = entry.guide.role.clone();
+                                let id = entry.id.clone();
+                                let link = LinkState {
+                                    god: Some(god_name.clone()),
+                                    build_role: Some(role.clone()),
+                                    class: filtered_class.clone(),
+                                    role: filtered_role.clone(),
+                                };
                                 move |_| {
                                     selected_god.set(SelectedGod(Some(god_name.clone())));
                                     selected_role_signal.set(SelectedRole(Some(role.clone())));
-                                    navigator().push(Route::Home);
+                                    selected_build.set(SelectedBuild(Some(id.clone())));
+                                    navigator().push(link.route(LinkScope::Home));
                                 }
                             },
                             
@@ -419,12 +425,12 @@ fn CheatsheetCard(
                                     style: "display: flex; align-items: center; gap: 8px;",
                                     
                                     // Build items
-                                    if !guide_data.build.is_empty() {
+                                    if !entry.guide.build.is_empty() {
                                         div {
                                             class: "item-grid",
                                             style: "display: grid; grid-template-columns: repeat(6, 1fr); gap: 2px;",
                                             
-                                            for item in guide_data.build.iter().take(6) {
+                                            for item in entry.guide.build.iter().take(6) {
                                                 Item { 
                                                     item: item.clone(),
                                                     size: 24
@@ -432,6 +438,13 @@ fn CheatsheetCard(
                                             }
                                         }
                                     }
+
+                                    span {
+                                        class: "guide-badge {entry.source.class()}",
+                                        style: "margin-left: 0;",
+                                        title: "{entry.label()}",
+                                        "{entry.source.label()}"
+                                    }
                                     
                                 }
                             }
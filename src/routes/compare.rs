@@ -0,0 +1,205 @@
+use dioxus::prelude::*;
+use crate::components::{Header, Tooltip, render_item_row, skill_row, SKILL_ORDER};
+use crate::data::build_stats::BuildStats;
+use crate::data::compare::{diff_items, skill_divergence, stat_deltas};
+use crate::data::gods::GODS;
+use crate::data::guide_sources::{all_guides, GuideEntry};
+use crate::data::saved_guides::SavedGuides;
+use crate::utils::format::{format_stat_name, format_stat_value};
+use crate::utils::format_god_image_name;
+use crate::Route;
+
+/// Two guides side by side, with their differences highlighted
+#[component]
+pub fn Compare(left: String, right: String) -> Element {
+    let saved_guides = use_context::<Signal<SavedGuides>>();
+
+    // Every guide, grouped by god in name order
+    let entries: Vec<GuideEntry> = {
+        let mut by_god: Vec<_> = all_guides(&saved_guides.read().0).into_iter().collect();
+        by_god.sort_by(|a, b| a.0.cmp(&b.0));
+        by_god.into_iter().flat_map(|(_, entries)| entries).collect()
+    };
+    let find = |id: &str| entries.iter().find(|entry| entry.id == id).cloned();
+    let left_entry = find(&left);
+    let right_entry = find(&right);
+
+    let left_guide = left_entry.as_ref().map(|entry| &entry.guide);
+    let right_guide = right_entry.as_ref().map(|entry| &entry.guide);
+    let skill_order = |entry: Option<&GuideEntry>| -> Vec<u8> {
+        match entry {
+            Some(entry) if !entry.guide.skill_order.is_empty() => entry.guide.skill_order.clone(),
+            Some(entry) => SKILL_ORDER.get(&entry.guide.god_id).cloned().unwrap_or_default(),
+            None => Vec::new(),
+        }
+    };
+    let left_skills = skill_order(left_entry.as_ref());
+    let right_skills = skill_order(right_entry.as_ref());
+
+    let build_diff = diff_items(
+        left_guide.map(|guide| guide.build.as_slice()).unwrap_or_default(),
+        right_guide.map(|guide| guide.build.as_slice()).unwrap_or_default(),
+    );
+    let relic_diff = diff_items(
+        left_guide.map(|guide| guide.relics.as_slice()).unwrap_or_default(),
+        right_guide.map(|guide| guide.relics.as_slice()).unwrap_or_default(),
+    );
+    let diverging = match (&left_entry, &right_entry) {
+        (Some(_), Some(_)) => skill_divergence(&left_skills, &right_skills),
+        _ => Vec::new(),
+    };
+    let deltas = match (left_guide, right_guide) {
+        (Some(left), Some(right)) => stat_deltas(&BuildStats::from_guide(left), &BuildStats::from_guide(right)),
+        _ => Vec::new(),
+    };
+
+    let columns = [
+        (left_entry, left.clone(), build_diff.only_left.clone(), relic_diff.only_left.clone(), left_skills, true),
+        (right_entry, right.clone(), build_diff.only_right.clone(), relic_diff.only_right.clone(), right_skills, false),
+    ];
+
+    rsx! {
+        div {
+            class: "container",
+            div {
+                style: "padding: 12px 24px; border-bottom: 1px solid var(--color-border);",
+                Header {}
+            }
+            div {
+                class: "main-content",
+                div {
+                    class: "compare-columns",
+                    for (entry, selected, only_build, only_relics, skills, is_left) in columns {
+                        div {
+                            class: "compare-column",
+                            select {
+                                value: "{selected}",
+                                onchange: {
+                                    let left = left.clone();
+                                    let right = right.clone();
+                                    move |evt: FormEvent| {
+                                        let (left, right) = if is_left {
+                                            (evt.value(), right.clone())
+                                        } else {
+                                            (left.clone(), evt.value())
+                                        };
+                                        navigator().replace(Route::Compare { left, right });
+                                    }
+                                },
+                                option { value: "", "Pick a guide…" }
+                                for option_entry in entries.iter() {
+                                    option {
+                                        key: "{option_entry.id}",
+                                        value: "{option_entry.id}",
+                                        selected: option_entry.id == selected,
+                                        {entry_label(option_entry)}
+                                    }
+                                }
+                            }
+
+                            if let Some(entry) = entry {
+                                div {
+                                    class: "explain-top",
+                                    img {
+                                        class: "god-img",
+                                        src: "/assets/gods/{format_god_image_name(&entry.guide.god_id)}.png"
+                                    }
+                                    div {
+                                        class: "explain-title",
+                                        h1 {
+                                            {entry_title(&entry)}
+                                            span {
+                                                class: "guide-badge {entry.source.class()}",
+                                                "{entry.source.label()}"
+                                            }
+                                        }
+                                        h4 { "{entry.guide.role}" }
+                                    }
+                                }
+
+                                h5 { "Build" }
+                                div {
+                                    class: "compare-items",
+                                    span { class: "compare-label", "Shared" }
+                                    {render_item_row(&build_diff.shared, Some(40))}
+                                }
+                                if !only_build.is_empty() {
+                                    div {
+                                        class: "compare-items differs",
+                                        span { class: "compare-label", "Only here" }
+                                        {render_item_row(&only_build, Some(40))}
+                                    }
+                                }
+
+                                h5 { "Relics" }
+                                div {
+                                    class: if only_relics.is_empty() { "compare-items" } else { "compare-items differs" },
+                                    {render_item_row(&entry.guide.relics, Some(40))}
+                                }
+
+                                h5 {
+                                    "Skill Order"
+                                    if !diverging.is_empty() {
+                                        " · differs at level "
+                                        {diverging.iter().map(|i| (i + 1).to_string()).collect::<Vec<_>>().join(", ")}
+                                    }
+                                }
+                                if let Some(god) = GODS.get(&entry.guide.god_id) {
+                                    div {
+                                        class: "grid_hold",
+                                        for (skill_idx, ability) in god.abilities.iter().enumerate().skip(1) {
+                                            {skill_row(ability, &skills, skill_idx - 1, None, &diverging)}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !deltas.is_empty() {
+                    h5 { "Stat Totals" }
+                    div {
+                        class: "compare-stats",
+                        span { class: "stat-head", "Stat" }
+                        span { class: "stat-head", "Left" }
+                        span { class: "stat-head", "Right" }
+                        span { class: "stat-head", "Δ" }
+                        for delta in deltas {
+                            span { "{format_stat_name(&delta.stat)}" }
+                            span { "{format_stat_value(&delta.stat, delta.left)}" }
+                            span { "{format_stat_value(&delta.stat, delta.right)}" }
+                            span {
+                                class: match delta.delta() {
+                                    0 => "delta",
+                                    d if d > 0 => "delta up",
+                                    _ => "delta down",
+                                },
+                                match delta.delta() {
+                                    0 => "—".to_string(),
+                                    d if d > 0 => format!("+{}", format_stat_value(&delta.stat, d)),
+                                    d => format!("-{}", format_stat_value(&delta.stat, -d)),
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Tooltip {}
+    }
+}
+
+fn god_name(entry: &GuideEntry) -> String {
+    GODS.get(&entry.guide.god_id)
+        .map(|god| god.display_name.clone())
+        .unwrap_or_else(|| entry.guide.god_id.clone())
+}
+
+fn entry_title(entry: &GuideEntry) -> String {
+    entry.guide.title.clone().unwrap_or_else(|| god_name(entry))
+}
+
+fn entry_label(entry: &GuideEntry) -> String {
+    format!("{} · {} · {}", god_name(entry), entry.guide.role, entry.label())
+}
@@ -1,41 +1,37 @@
 use dioxus::prelude::*;
-use crate::components::{Header, Ability, MarkdownRenderer};
+use crate::components::{Header, Ability, MarkdownRenderer, BuildStatsTable};
 use crate::data::gods::GODS;
-use crate::data::items::{ITEMS, Effect};
-use crate::{SelectedGod, SelectedRole};
-use crate::utils::format_god_image_name;
-use serde::{Deserialize, Serialize};
+use crate::data::items::{ITEMS, Effect, ItemStat, ItemTag};
+use crate::data::item_filter::ItemFilter;
+use crate::data::item_tree::total_cost;
+use crate::data::build_stats::BuildStats;
+use crate::data::guides::{Guide, TimelineEntry};
+use crate::data::migrations::parse_guide;
+use crate::data::patches::current_patch;
+use crate::data::search::fuzzy_matches;
+use crate::data::share::decode_guide;
+use crate::data::saved_guides::{clear_draft, load_draft, save_draft, Draft, SavedGuide, SavedGuides};
+use crate::data::validation::{can_level_skill, validate_guide, Diagnostic};
+use crate::{SelectedGod, SelectedRole, Route};
+use crate::utils::{format_god_image_name, download_file};
+use crate::utils::format::format_stat_label;
+use crate::utils::history::History;
+use crate::utils::{clear_timeout, set_timeout};
+use std::cell::RefCell;
+use dioxus::html::{FileEngine, HasFileData};
+use std::rc::Rc;
+use std::sync::Arc;
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::window;
 
-#[derive(Clone, Debug, PartialEq)]
-struct TimelineEntry {
-    time: String,
-    items: Vec<String>,
-    description: String,
-    progress: f64, // 0.0 to 100.0 representing position on timeline
-}
-
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-struct SavedBuild {
-    id: String,
-    title: String,
-    god: String,
-    role: String,
-    build: Vec<String>,
-    relics: Vec<String>,
-    timeline: Vec<SavedTimelineEntry>,
-    skill_order: Vec<u8>,
-    strategy: String,
-    created_at: String,
-}
+/// Most edits the creator can undo
+const MAX_UNDO_STEPS: usize = 100;
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-struct SavedTimelineEntry {
-    time: String,
-    items: Vec<String>,
-    description: String,
-    progress: f64,
-}
+/// Quiet time after an edit before the draft is written
+const AUTOSAVE_DELAY_MS: i32 = 500;
 
+/// Editor state. Text fields use empty strings where `Guide` uses `None`;
+/// everything else is the canonical `Guide` shape so conversion is lossless.
 #[derive(Clone, Debug, PartialEq)]
 struct GuideData {
     title: String,
@@ -46,30 +42,307 @@ struct GuideData {
     timeline: Vec<TimelineEntry>,
     skill_order: Vec<u8>,
     strategy: String,
+    patch: Option<String>,
+}
+
+impl GuideData {
+    /// An empty guide for a god and role
+    fn blank(god: String, role: String) -> Self {
+        GuideData::from_guide(Guide::new(god, role))
+    }
+
+    /// Load a guide into the editor
+    fn from_guide(guide: Guide) -> Self {
+        GuideData {
+            title: guide.title.unwrap_or_default(),
+            god: guide.god_id,
+            role: guide.role,
+            build: guide.build,
+            relics: guide.relics,
+            timeline: guide.timeline,
+            skill_order: guide.skill_order,
+            strategy: guide.strategy.unwrap_or_default(),
+            patch: guide.patch,
+        }
+    }
+
+    /// The guide as it would be published, for validation, saving and export
+    fn to_guide(&self) -> Guide {
+        let non_empty = |text: &String| if text.is_empty() { None } else { Some(text.clone()) };
+
+        Guide {
+            title: non_empty(&self.title),
+            build: self.build.clone(),
+            relics: self.relics.clone(),
+            timeline: self.timeline.clone(),
+            skill_order: self.skill_order.clone(),
+            strategy: non_empty(&self.strategy),
+            patch: self.patch.clone(),
+            ..Guide::new(self.god.clone(), self.role.clone())
+        }
+    }
+
+    /// The guide as stored by "Save Guide"
+    fn to_saved_guide(&self) -> Guide {
+        let mut guide = self.to_guide();
+        guide.title.get_or_insert_with(|| "Untitled Guide".to_string());
+        guide
+    }
+
+    /// What the draft slot should hold for this state: nothing when there's
+    /// nothing unsaved (an empty new guide, or an unchanged saved one)
+    fn draft(&self, saved: Option<&SavedGuide>) -> Option<Draft> {
+        let guide = self.to_guide();
+        let unchanged = match saved {
+            Some(saved) => saved.guide == self.to_saved_guide(),
+            None => guide == Guide::new(self.god.clone(), self.role.clone()),
+        };
+        if unchanged {
+            return None;
+        }
+        Some(Draft { saved_id: saved.map(|saved| saved.id.clone()), guide })
+    }
+
+    /// The typed-in field an edit touched, if that's all it touched,
+    /// so that typing undoes as one step rather than per keystroke
+    fn merge_key(before: &GuideData, after: &GuideData) -> Option<&'static str> {
+        let title_only = GuideData { title: after.title.clone(), ..before.clone() } == *after;
+        let strategy_only = GuideData { strategy: after.strategy.clone(), ..before.clone() } == *after;
+        // Timeline tips and percents each undo on their own
+        let timeline_only = |same: fn(&TimelineEntry, &TimelineEntry) -> bool| {
+            GuideData { timeline: after.timeline.clone(), ..before.clone() } == *after
+                && before.timeline.len() == after.timeline.len()
+                && before.timeline.iter().zip(&after.timeline).all(|(a, b)| same(a, b))
+        };
+
+        if title_only {
+            Some("title")
+        } else if strategy_only {
+            Some("strategy")
+        } else if timeline_only(|a, b| a.items == b.items && a.percent == b.percent) {
+            Some("timeline_tip")
+        } else if timeline_only(|a, b| a.items == b.items && a.tip == b.tip) {
+            Some("timeline_percent")
+        } else {
+            None
+        }
+    }
+}
+
+/// Debounced writes to the creator's draft slot
+struct Autosave {
+    editing: Option<String>, // ID of the saved guide being edited, None for a new guide
+    pending: Option<Option<Draft>>, // `Some(None)` clears the slot
+    timeout: Option<i32>,
+}
+
+impl Autosave {
+    fn schedule(autosave: &Rc<RefCell<Autosave>>, draft: Option<Draft>) {
+        let mut this = autosave.borrow_mut();
+        if let Some(handle) = this.timeout.take() {
+            clear_timeout(handle);
+        }
+        this.pending = Some(draft);
+        let autosave = autosave.clone();
+        this.timeout = set_timeout(AUTOSAVE_DELAY_MS, move || autosave.borrow_mut().flush());
+    }
+
+    fn flush(&mut self) {
+        if let Some(handle) = self.timeout.take() {
+            clear_timeout(handle);
+        }
+        match self.pending.take() {
+            Some(Some(draft)) => save_draft(self.editing.as_deref(), &draft),
+            Some(None) => clear_draft(self.editing.as_deref()),
+            None => {}
+        }
+    }
+}
+
+fn undo_edit(mut history: Signal<History<GuideData>>, mut guide_data: Signal<GuideData>) {
+    let restored = history.write().undo();
+    if let Some(data) = restored {
+        guide_data.set(data);
+    }
+}
+
+fn redo_edit(mut history: Signal<History<GuideData>>, mut guide_data: Signal<GuideData>) {
+    let restored = history.write().redo();
+    if let Some(data) = restored {
+        guide_data.set(data);
+    }
 }
 
 #[component]
 pub fn GuideCreator() -> Element {
+    rsx! { GuideEditor { saved: None } }
+}
+
+/// Puts a copy of another guide, passed as a share code, in the new-guide
+/// draft and moves on to `/guide/create`, so a refresh keeps the copy.
+/// An unsaved guide already in the draft is only replaced if the user agrees.
+#[component]
+pub fn GuideFork(code: String) -> Element {
+    // Ok(false) when the user kept their draft
+    let forked = use_hook(|| decode_guide(&code).map(|guide| {
+        let replace = match load_draft(None) {
+            Some(draft) if draft.guide != guide => window()
+                .and_then(|w| w.confirm_with_message("Replace the unsaved guide in the creator?").ok())
+                .unwrap_or(false),
+            _ => true,
+        };
+        if replace {
+            save_draft(None, &Draft { saved_id: None, guide });
+        }
+        replace
+    }));
+    use_effect({
+        let forked = forked == Ok(true);
+        move || {
+            if forked {
+                navigator().replace(Route::GuideCreator);
+            }
+        }
+    });
+
+    match forked {
+        Ok(true) => rsx! {},
+        Ok(false) => rsx! {
+            div {
+                style: "padding: 24px;",
+                Header {}
+                p { "Kept the unsaved guide in the creator." }
+                Link { to: Route::GuideCreator, "Back to the creator" }
+            }
+        },
+        Err(e) => rsx! {
+            div {
+                style: "padding: 24px;",
+                Header {}
+                p { "Couldn't open this guide for editing: {e}" }
+            }
+        },
+    }
+}
+
+/// The creator opened on a guide from "My Guides"
+#[component]
+pub fn GuideEdit(id: String) -> Element {
+    let saved_guides = use_context::<Signal<SavedGuides>>();
+    let saved = saved_guides.read().get(&id).cloned();
+    let Some(saved) = saved else {
+        return rsx! {
+            div {
+                style: "padding: 24px;",
+                Header {}
+                p { "Saved guide not found." }
+            }
+        };
+    };
+
+    rsx! { GuideEditor { key: "{id}", saved: Some(saved) } }
+}
+
+#[component]
+fn GuideEditor(saved: Option<SavedGuide>) -> Element {
     // Get context for selected god and role
     let selected_god = use_context::<Signal<SelectedGod>>();
     let selected_role = use_context::<Signal<SelectedRole>>();
+    let mut saved_guides = use_context::<Signal<SavedGuides>>();
     
+    // Pick up where the last session left off if a draft was autosaved
+    let editing = saved.is_some();
+    let draft = use_hook(|| load_draft(saved.as_ref().map(|saved| saved.id.as_str())));
+
     // Initialize form data
-    let mut guide_data = use_signal(|| {
-        GuideData {
-            title: String::new(),
-            god: selected_god().0.unwrap_or_default(),
-            role: selected_role().0.unwrap_or_default(),
-            build: vec![],
-            relics: vec![],
-            timeline: vec![],
-            skill_order: vec![],
-            strategy: String::new(),
+    let mut guide_data = use_signal(|| match (&draft, &saved) {
+        (Some(draft), _) => GuideData::from_guide(draft.guide.clone()),
+        (None, Some(saved)) => GuideData::from_guide(saved.guide.clone()),
+        (None, None) => GuideData::blank(
+            selected_god().0.unwrap_or_default(),
+            selected_role().0.unwrap_or_default(),
+        ),
+    });
+
+    // Undo/redo over every change to `guide_data`. Undo and redo set the signal
+    // to the history's current value, so they aren't recorded again.
+    let mut history = use_signal(|| History::new(guide_data.peek().clone(), MAX_UNDO_STEPS));
+    use_effect(move || {
+        let data = guide_data();
+        let mut history = history.write();
+        if data != *history.current() {
+            let merge_key = GuideData::merge_key(history.current(), &data);
+            history.record(data, merge_key, js_sys::Date::now());
         }
     });
-    
+
+    // Ctrl+Z / Ctrl+Shift+Z (or Ctrl+Y) anywhere in the editor
+    let keydown = use_hook(|| {
+        let closure = Closure::<dyn FnMut(_)>::new(move |event: web_sys::KeyboardEvent| {
+            if !(event.ctrl_key() || event.meta_key()) || event.alt_key() {
+                return;
+            }
+            match event.key().to_lowercase().as_str() {
+                "z" if event.shift_key() => {
+                    event.prevent_default();
+                    redo_edit(history, guide_data);
+                }
+                "z" => {
+                    event.prevent_default();
+                    undo_edit(history, guide_data);
+                }
+                "y" => {
+                    event.prevent_default();
+                    redo_edit(history, guide_data);
+                }
+                _ => {}
+            }
+        });
+        if let Some(document) = window().and_then(|w| w.document()) {
+            let _ = document.add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
+        }
+        Rc::new(closure)
+    });
+    use_drop(move || {
+        if let Some(document) = window().and_then(|w| w.document()) {
+            let _ = document.remove_event_listener_with_callback("keydown", keydown.as_ref().as_ref().unchecked_ref());
+        }
+    });
+
+    // The stored guide being edited, set once it has been saved
+    let mut saved_guide = use_signal(|| match (&saved, &draft) {
+        (Some(saved), _) => Some(saved.clone()),
+        (None, Some(draft)) => draft.saved_id.as_deref().and_then(|id| saved_guides.peek().get(id).cloned()),
+        (None, None) => None,
+    });
+
     // UI state
     let search_query = use_signal(String::new);
+    let mut import_status = use_signal(|| {
+        let restored = draft.as_ref().is_some_and(|draft| {
+            let draft_guide = GuideData::from_guide(draft.guide.clone()).to_saved_guide();
+            saved_guide.peek().as_ref().map(|saved| &saved.guide) != Some(&draft_guide)
+        });
+        restored.then(|| Ok("Restored unsaved draft".to_string()))
+    });
+
+    // Autosave the draft shortly after each edit, and right away when leaving
+    let autosave = use_hook(|| Rc::new(RefCell::new(Autosave {
+        editing: saved.as_ref().map(|saved| saved.id.clone()),
+        pending: None,
+        timeout: None,
+    })));
+    use_effect({
+        let autosave = autosave.clone();
+        move || {
+            let draft = guide_data().draft(saved_guide.peek().as_ref());
+            Autosave::schedule(&autosave, draft);
+        }
+    });
+    use_drop({
+        let autosave = autosave.clone();
+        move || autosave.borrow_mut().flush()
+    });
     
     // Get god info
     let god_info = if guide_data().god.is_empty() { 
@@ -77,11 +350,20 @@ pub fn GuideCreator() -> Element {
     } else { 
         GODS.get(&guide_data().god).cloned()
     };
+
+    let diagnostics = validate_guide(&guide_data().to_guide());
     
     rsx! {
         div {
             class: "guide-creator-container",
             style: "display: flex; flex-direction: column; height: 100vh; background: var(--color-bg-primary);",
+            ondragover: move |evt| evt.prevent_default(),
+            ondrop: move |evt: DragEvent| async move {
+                evt.prevent_default();
+                if let Some(files) = evt.files() {
+                    import_status.set(Some(import_guide_file(files, guide_data).await));
+                }
+            },
             
             // Header - simplified like cheatsheet
             div {
@@ -119,44 +401,136 @@ pub fn GuideCreator() -> Element {
                     
                     // Spacer
                     div { style: "flex: 1;" }
+
+                    // Undo / redo
+                    div {
+                        style: "display: flex; gap: 4px;",
+                        button {
+                            style: format!(
+                                "padding: 8px 12px; background: var(--color-bg-secondary); border: 1px solid var(--color-border); border-radius: 6px; color: var(--color-text-primary); cursor: pointer; opacity: {};",
+                                if history.read().can_undo() { "1" } else { "0.4" }
+                            ),
+                            title: "Undo (Ctrl+Z)",
+                            disabled: !history.read().can_undo(),
+                            onclick: move |_| undo_edit(history, guide_data),
+                            "Undo"
+                        }
+                        button {
+                            style: format!(
+                                "padding: 8px 12px; background: var(--color-bg-secondary); border: 1px solid var(--color-border); border-radius: 6px; color: var(--color-text-primary); cursor: pointer; opacity: {};",
+                                if history.read().can_redo() { "1" } else { "0.4" }
+                            ),
+                            title: "Redo (Ctrl+Shift+Z)",
+                            disabled: !history.read().can_redo(),
+                            onclick: move |_| redo_edit(history, guide_data),
+                            "Redo"
+                        }
+                    }
+
+                    if let Some(status) = import_status() {
+                        span {
+                            style: format!(
+                                "font-size: 12px; color: {};",
+                                if status.is_ok() { "var(--color-text-secondary)" } else { "#dc2626" }
+                            ),
+                            match &status {
+                                Ok(message) | Err(message) => message.clone(),
+                            }
+                        }
+                    }
+
+                    // Import from a .json file
+                    label {
+                        style: "padding: 8px 16px; background: var(--color-bg-secondary); border: 1px solid var(--color-border); border-radius: 6px; color: var(--color-text-primary); font-weight: 600; cursor: pointer;",
+                        title: "Load a guide .json file (or drop one anywhere on the page)",
+                        "Import"
+                        input {
+                            r#type: "file",
+                            accept: ".json,application/json",
+                            style: "display: none;",
+                            onchange: move |evt: FormEvent| async move {
+                                if let Some(files) = evt.files() {
+                                    import_status.set(Some(import_guide_file(files, guide_data).await));
+                                }
+                            },
+                        }
+                    }
+
+                    // Export as a .json file in the guides.json schema
+                    button {
+                        style: "padding: 8px 16px; background: var(--color-bg-secondary); border: 1px solid var(--color-border); border-radius: 6px; color: var(--color-text-primary); font-weight: 600; cursor: pointer;",
+                        onclick: move |_| {
+                            let guide = guide_data().to_guide();
+                            let filename = format!(
+                                "{}_{}.json",
+                                if guide.god_id.is_empty() { "guide" } else { &guide.god_id },
+                                guide.role.to_lowercase()
+                            );
+                            match serde_json::to_string_pretty(&guide) {
+                                Ok(json) if download_file(&filename, "application/json", &json) => {
+                                    import_status.set(Some(Ok(format!("Exported {}", filename))));
+                                }
+                                _ => import_status.set(Some(Err("Export failed".to_string()))),
+                            }
+                        },
+                        "Export"
+                    }
                     
+                    // Throw away unsaved changes
+                    if guide_data().draft(saved_guide().as_ref()).is_some() {
+                        button {
+                            style: "padding: 8px 16px; background: transparent; border: 1px solid var(--color-border); border-radius: 6px; color: var(--color-text-secondary); font-weight: 600; cursor: pointer;",
+                            title: if editing { "Go back to the last saved version" } else { "Start over with an empty guide" },
+                            onclick: move |_| {
+                                let confirmed = window()
+                                    .and_then(|w| w.confirm_with_message("Discard this draft?").ok())
+                                    .unwrap_or(false);
+                                if !confirmed {
+                                    return;
+                                }
+                                let data = guide_data();
+                                match saved_guide() {
+                                    Some(saved) if editing => guide_data.set(GuideData::from_guide(saved.guide)),
+                                    _ => {
+                                        saved_guide.set(None);
+                                        guide_data.set(GuideData::blank(data.god, data.role));
+                                    }
+                                }
+                                import_status.set(Some(Ok("Draft discarded".to_string())));
+                            },
+                            "Discard draft"
+                        }
+                    }
+
                     // Save button
                     button {
                         style: "padding: 8px 16px; background: var(--color-accent); border: none; border-radius: 6px; color: white; font-weight: 600; cursor: pointer;",
-                        onclick: move |_| {
-                            let data = guide_data();
-                            let timestamp = js_sys::Date::now() as u64;
-                            let id = format!("build_{}", timestamp);
-                            
-                            let saved_build = SavedBuild {
-                                id: id.clone(),
-                                title: if data.title.is_empty() { "Untitled Guide".to_string() } else { data.title },
-                                god: data.god,
-                                role: data.role,
-                                build: data.build,
-                                relics: data.relics,
-                                timeline: data.timeline.into_iter().map(|entry| SavedTimelineEntry {
-                                    time: entry.time,
-                                    items: entry.items,
-                                    description: entry.description,
-                                    progress: entry.progress,
-                                }).collect(),
-                                skill_order: data.skill_order,
-                                strategy: data.strategy,
-                                created_at: format!("{}", timestamp),
-                            };
-                            
-                            // Save to browser's localStorage as a JSON file equivalent
-                            if let Ok(json) = serde_json::to_string_pretty(&saved_build) {
-                                // Save to localStorage with the unique ID as key
-                                if let Some(window) = web_sys::window() {
-                                    if let Ok(Some(storage)) = window.local_storage() {
-                                        let _ = storage.set_item(&format!("grappul_build_{}", id), &json);
-                                        web_sys::console::log_1(&format!("Build saved with ID: {}", id).into());
-                                        
-                                        // Also log the JSON for debugging
-                                        log::info!("Saved build JSON: {}", json);
-                                    }
+                        onclick: {
+                            let autosave = autosave.clone();
+                            move |_| {
+                                // Saving vouches for the guide on the current patch
+                                guide_data.write().patch = Some(current_patch().id.to_string());
+                                let guide = guide_data().to_saved_guide();
+
+                                // Save to browser's localStorage under grappul_build_<timestamp>,
+                                // overwriting the guide being edited if there is one
+                                let saved = match saved_guide() {
+                                    Some(existing) => SavedGuide { guide, ..existing },
+                                    None => SavedGuide::new(guide, js_sys::Date::now() as u64),
+                                };
+                                saved_guides.write().save(saved.clone());
+                                log::info!("Guide saved with ID: {}", saved.id);
+                                import_status.set(Some(Ok("Saved".to_string())));
+
+                                // Nothing is unsaved any more, so the draft goes away
+                                Autosave::schedule(&autosave, guide_data().draft(Some(&saved)));
+                                autosave.borrow_mut().flush();
+
+                                // A new guide carries on at its edit URL, so a refresh reopens it
+                                if editing {
+                                    saved_guide.set(Some(saved));
+                                } else {
+                                    navigator().replace(Route::GuideEdit { id: saved.id });
                                 }
                             }
                         },
@@ -169,13 +543,29 @@ pub fn GuideCreator() -> Element {
             div {
                 style: "flex: 1; overflow-y: auto; padding: 24px 24px 400px 24px; scroll-behavior: smooth;",
                 
+                GuideDiagnostics { diagnostics: diagnostics.clone(), fields: vec!["god_id", "role"] }
+
                 // Build and Items section
-                div { id: "build", BuildAndItemsSection { guide_data: guide_data, search_query: search_query } }
+                div {
+                    id: "build",
+                    GuideDiagnostics { diagnostics: diagnostics.clone(), fields: vec!["build", "relics"] }
+                    BuildAndItemsSection { guide_data: guide_data, search_query: search_query }
+                }
                 
-                div { style: "margin-top: 48px;", id: "skills", SkillOrderSection { guide_data: guide_data, god_info: god_info } }
+                div {
+                    style: "margin-top: 48px;",
+                    id: "skills",
+                    GuideDiagnostics { diagnostics: diagnostics.clone(), fields: vec!["skill_order"] }
+                    SkillOrderSection { guide_data: guide_data, god_info: god_info }
+                }
                 
                 // Timeline section - full width
-                div { style: "margin-top: 48px;", id: "timeline", TimelineSection { guide_data: guide_data } }
+                div {
+                    style: "margin-top: 48px;",
+                    id: "timeline",
+                    GuideDiagnostics { diagnostics: diagnostics, fields: vec!["timeline"] }
+                    TimelineSection { guide_data: guide_data }
+                }
                 
                 // Strategy Guide section - full width
                 div { style: "margin-top: 48px;", id: "strategy", StrategyGuideSection { guide_data: guide_data } }
@@ -185,6 +575,70 @@ pub fn GuideCreator() -> Element {
 }
 
 
+/// Read the first file as a `Guide` and load it into the editor
+async fn import_guide_file(files: Arc<dyn FileEngine>, mut guide_data: Signal<GuideData>) -> Result<String, String> {
+    let Some(name) = files.files().into_iter().next() else {
+        return Err("No file selected".to_string());
+    };
+    let Some(contents) = files.read_file_to_string(&name).await else {
+        return Err(format!("Couldn't read {}", name));
+    };
+    let guide = parse_guide(&contents)
+        .map_err(|e| format!("{} is not a valid guide: {}", name, e))?;
+
+    let errors = validate_guide(&guide).iter().filter(|d| d.is_error()).count();
+    if errors > 0 {
+        let problems = if errors == 1 { "1 problem".to_string() } else { format!("{} problems", errors) };
+        let confirmed = window()
+            .and_then(|w| w.confirm_with_message(&format!("{} has {}. Replace the current guide with it anyway?", name, problems)).ok())
+            .unwrap_or(false);
+        if !confirmed {
+            return Err(format!("Didn't import {}", name));
+        }
+    }
+    guide_data.set(GuideData::from_guide(guide));
+
+    Ok(match errors {
+        0 => format!("Imported {}", name),
+        1 => format!("Imported {} with 1 problem", name),
+        n => format!("Imported {} with {} problems", name, n),
+    })
+}
+
+/// Inline list of validation problems for the given guide fields
+#[component]
+fn GuideDiagnostics(diagnostics: Vec<Diagnostic>, fields: Vec<&'static str>) -> Element {
+    let relevant: Vec<Diagnostic> = diagnostics.into_iter()
+        .filter(|d| fields.iter().any(|field| {
+            d.path == *field || d.path.starts_with(&format!("{}[", field)) || d.path.starts_with(&format!("{}.", field))
+        }))
+        .collect();
+
+    if relevant.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            style: "display: flex; flex-direction: column; gap: 4px; margin-bottom: 16px;",
+            for (i, diagnostic) in relevant.iter().enumerate() {
+                div {
+                    key: "{i}",
+                    style: format!(
+                        "padding: 6px 12px; border-radius: 4px; font-size: 12px; border-left: 3px solid {}; background: var(--color-bg-tertiary); color: var(--color-text-primary);",
+                        if diagnostic.is_error() { "#dc2626" } else { "#f59e0b" }
+                    ),
+                    span {
+                        style: "font-family: monospace; color: var(--color-text-secondary); margin-right: 8px;",
+                        "{diagnostic.path}"
+                    }
+                    "{diagnostic.message}"
+                }
+            }
+        }
+    }
+}
+
 #[component]
 fn GodSelector(guide_data: Signal<GuideData>) -> Element {
     let mut available_gods: Vec<_> = GODS.keys().cloned().collect();
@@ -195,7 +649,7 @@ fn GodSelector(guide_data: Signal<GuideData>) -> Element {
     // Filter gods based on search
     let filtered_gods: Vec<_> = available_gods.iter()
         .filter(|god| {
-            search_god().is_empty() || god.to_lowercase().contains(&search_god().to_lowercase())
+            fuzzy_matches(&search_god(), &[god.as_str()])
         })
         .cloned()
         .collect();
@@ -310,25 +764,6 @@ fn BuildSection(guide_data: Signal<GuideData>, search_query: Signal<String>) ->
     let mut tier3_enabled = use_signal(|| true);
     let mut dragged_item_index = use_signal(|| None::<usize>);
     
-    // Helper function to get the effective price for sorting (evolved items use base price)
-    let get_effective_price = |item_name: &str| -> u32 {
-        if let Some(item) = ITEMS.get(item_name) {
-            // If price is 0, check if it's an evolved item with a base item
-            if item.price == 0 {
-                // Check if this item has a glyph effect
-                if item.effects.contains_key(&Effect::Glyph) {
-                    // For evolved items, use the base item's price
-                    // This is a simplification - in the full implementation,
-                    // we might extract the base item name from the effect string
-                    return 3000; // Default glyph item price
-                }
-            }
-            item.price
-        } else {
-            0
-        }
-    };
-    
     // Helper function to determine item tier
     let get_item_tier = |item_name: &str| -> u8 {
         if let Some(item) = ITEMS.get(item_name) {
@@ -364,10 +799,11 @@ fn BuildSection(guide_data: Signal<GuideData>, search_query: Signal<String>) ->
     };
     
     // Separate starter items, glyph items, and regular items
+    let item_filter = ItemFilter::parse(&search_query());
     let mut starter_items: Vec<_> = ITEMS.iter()
-        .filter(|(item_name, _item_data)| {
+        .filter(|(item_name, item_data)| {
             // Apply search filter
-            let search_matches = search_query().is_empty() || item_name.contains(&search_query().to_lowercase());
+            let search_matches = item_filter.matches(item_name, item_data);
             if !search_matches {
                 return false;
             }
@@ -380,13 +816,13 @@ fn BuildSection(guide_data: Signal<GuideData>, search_query: Signal<String>) ->
             // Only starter items
             item_name.starts_with("s_")
         })
-        .map(|(item_name, _item_data)| (item_name.clone(), get_effective_price(item_name)))
+        .map(|(item_name, _item_data)| (item_name.clone(), total_cost(item_name)))
         .collect();
     
     let mut glyph_items: Vec<_> = ITEMS.iter()
-        .filter(|(item_name, _item_data)| {
+        .filter(|(item_name, item_data)| {
             // Apply search filter
-            let search_matches = search_query().is_empty() || item_name.contains(&search_query().to_lowercase());
+            let search_matches = item_filter.matches(item_name, item_data);
             if !search_matches {
                 return false;
             }
@@ -404,13 +840,13 @@ fn BuildSection(guide_data: Signal<GuideData>, search_query: Signal<String>) ->
             // Only glyph items
             is_glyph_item(item_name)
         })
-        .map(|(item_name, _item_data)| (item_name.clone(), get_effective_price(item_name)))
+        .map(|(item_name, _item_data)| (item_name.clone(), total_cost(item_name)))
         .collect();
     
     let mut regular_items: Vec<_> = ITEMS.iter()
-        .filter(|(item_name, _item_data)| {
+        .filter(|(item_name, item_data)| {
             // Apply search filter
-            let search_matches = search_query().is_empty() || item_name.contains(&search_query().to_lowercase());
+            let search_matches = item_filter.matches(item_name, item_data);
             if !search_matches {
                 return false;
             }
@@ -439,7 +875,7 @@ fn BuildSection(guide_data: Signal<GuideData>, search_query: Signal<String>) ->
                 _ => true,
             }
         })
-        .map(|(item_name, _item_data)| (item_name.clone(), get_effective_price(item_name)))
+        .map(|(item_name, _item_data)| (item_name.clone(), total_cost(item_name)))
         .collect();
     
     // Sort all by price ascending
@@ -857,21 +1293,6 @@ fn BuildAndItemsSection(guide_data: Signal<GuideData>, search_query: Signal<Stri
     let mut tier3_enabled = use_signal(|| true);
     let mut dragged_item_index = use_signal(|| None::<usize>);
     
-    // Helper function to get the effective price for sorting
-    let get_effective_price = |item_name: &str| -> u32 {
-        if let Some(item) = ITEMS.get(item_name) {
-            if item.price == 0 {
-                if item.effects.contains_key(&Effect::Glyph) {
-                    // For glyph items, use a default price since we don't have base item info
-                    return 3000;
-                }
-            }
-            item.price
-        } else {
-            0
-        }
-    };
-    
     // Helper function to determine item tier from tags
     let get_item_tier = |item_name: &str| -> u8 {
         if let Some(item) = ITEMS.get(item_name) {
@@ -936,9 +1357,17 @@ fn BuildAndItemsSection(guide_data: Signal<GuideData>, search_query: Signal<Stri
     };
     
     // Helper function to check if an item should be faded (search filtered but still visible)
+    let item_filter = ItemFilter::parse(&search_query());
     let should_fade_item = |item_name: &str| -> bool {
-        // Only fade if search doesn't match
-        !search_query().is_empty() && !item_name.contains(&search_query().to_lowercase())
+        // Only fade if the picker query doesn't match
+        ITEMS.get(item_name).is_none_or(|item| !item_filter.matches(item_name, item))
+    };
+
+    // Append a filter token to the picker query
+    let mut add_filter = move |token: String| {
+        let query = search_query();
+        let query = query.trim();
+        search_query.set(if query.is_empty() { token } else { format!("{} {}", query, token) });
     };
     
     // Get all items categorized using tags
@@ -1166,21 +1595,11 @@ fn BuildAndItemsSection(guide_data: Signal<GuideData>, search_query: Signal<Stri
                                 span {
                                     style: "font-size: 16px; font-weight: 700; color: var(--color-accent);",
                                     {
-                                        let total_cost: u32 = guide_data().build.iter()
-                                            .filter_map(|item_name| ITEMS.get(item_name))
-                                            .map(|item| {
-                                                // For evolved items with 0 price, get base item price
-                                                if item.price == 0 {
-                                                    if item.effects.contains_key(&Effect::Glyph) {
-                                                        // For glyph items, use a default price
-                                                        return 3000;
-                                                    }
-                                                }
-                                                item.price
-                                            })
+                                        let build_cost: u32 = guide_data().build.iter()
+                                            .map(|item_name| total_cost(item_name))
                                             .sum();
                                         {
-                                            let cost_str = total_cost.to_string();
+                                            let cost_str = build_cost.to_string();
                                             let chars: Vec<char> = cost_str.chars().collect();
                                             let mut result = String::new();
                                             let len = chars.len();
@@ -1251,11 +1670,84 @@ fn BuildAndItemsSection(guide_data: Signal<GuideData>, search_query: Signal<Stri
                     
                     input {
                         r#type: "text",
-                        placeholder: "Search items...",
+                        placeholder: "Search items or filter...",
                         value: "{search_query}",
                         oninput: move |evt| search_query.set(evt.value()),
                         style: "width: 100%; padding: 6px 8px; border: 1px solid var(--color-border); border-radius: 4px; background: var(--color-bg-primary); font-size: 13px;",
                     }
+
+                    // Filter shortcuts. Each adds a token to the query; the key
+                    // remounts the selects so they go back to their placeholder.
+                    div {
+                        key: "{search_query}",
+                        style: "display: flex; flex-wrap: wrap; gap: 6px; margin-top: 8px;",
+
+                        select {
+                            style: "padding: 4px; border: 1px solid var(--color-border); border-radius: 4px; background: var(--color-bg-primary); color: var(--color-text-primary); font-size: 12px;",
+                            onchange: move |evt| add_filter(format!("has:{}", evt.value())),
+                            option { value: "", "+ Stat" }
+                            for stat in ItemStat::ALL {
+                                option {
+                                    value: "{stat:?}",
+                                    "{format_stat_label(&stat)}"
+                                }
+                            }
+                        }
+
+                        select {
+                            style: "padding: 4px; border: 1px solid var(--color-border); border-radius: 4px; background: var(--color-bg-primary); color: var(--color-text-primary); font-size: 12px;",
+                            onchange: move |evt| add_filter(format!("effect:{}", evt.value())),
+                            option { value: "", "+ Effect" }
+                            for effect in Effect::ALL {
+                                option { value: "{effect:?}", "{effect:?}" }
+                            }
+                        }
+
+                        select {
+                            style: "padding: 4px; border: 1px solid var(--color-border); border-radius: 4px; background: var(--color-bg-primary); color: var(--color-text-primary); font-size: 12px;",
+                            onchange: move |evt| add_filter(format!("tag:{}", evt.value())),
+                            option { value: "", "+ Tag" }
+                            for tag in ItemTag::ALL {
+                                option { value: "{tag:?}", "{tag:?}" }
+                            }
+                        }
+
+                        if !item_filter.is_empty() {
+                            button {
+                                style: "padding: 4px 8px; background: transparent; border: 1px solid var(--color-border); border-radius: 4px; color: var(--color-text-secondary); cursor: pointer; font-size: 12px;",
+                                onclick: move |_| search_query.set(String::new()),
+                                "Clear"
+                            }
+                        }
+                    }
+
+                    if !item_filter.unknown.is_empty() {
+                        p {
+                            style: "margin: 6px 0 0 0; font-size: 12px; color: #dc2626;",
+                            { format!("Unknown filter: {}", item_filter.unknown.join(", ")) }
+                        }
+                    }
+
+                    p {
+                        style: "margin: 6px 0 0 0; font-size: 11px; color: var(--color-text-secondary);",
+                        "e.g. has:magpen has:cdr price:<2600 \"anti-heal\""
+                    }
+                }
+            }
+            
+            // Stat totals for the current build
+            if !guide_data().build.is_empty() {
+                div {
+                    style: "margin-bottom: 24px; padding: 16px; background: var(--color-bg-secondary); border-radius: 8px;",
+                    
+                    h4 {
+                        style: "margin: 0 0 12px 0; font-size: 14px; font-weight: 600;",
+                        "Build Stats"
+                    }
+                    
+                    BuildStatsTable {
+                        stats: BuildStats::from_items(guide_data().build.iter().chain(guide_data().relics.iter()))
+                    }
                 }
             }
             
@@ -1643,14 +2135,13 @@ fn TimelineSection(guide_data: Signal<GuideData>) -> Element {
                                     // Create new checkpoint
                                     let mut data = guide_data();
                                     data.timeline.push(TimelineEntry {
-                                        time: format!("{}%", progress as u32),
-                                        items: vec![],
-                                        description: "New checkpoint".to_string(),
-                                        progress,
+                                        percent: progress.round() as u8,
+                                        items: None,
+                                        tip: Some("New checkpoint".to_string()),
                                     });
                                     
-                                    // Sort by progress
-                                    data.timeline.sort_by(|a, b| a.progress.partial_cmp(&b.progress).unwrap());
+                                    // Sort by position
+                                    data.timeline.sort_by_key(|entry| entry.percent);
                                     guide_data.set(data);
                                 }
                             }
@@ -1668,12 +2159,12 @@ fn TimelineSection(guide_data: Signal<GuideData>) -> Element {
                             key: "{idx}",
                             style: format!(
                                 "position: absolute; left: {}%; top: 50%; transform: translate(-50%, -50%); width: 20px; height: 20px; background: var(--color-accent); border: 3px solid white; border-radius: 50%; cursor: pointer; z-index: 10;",
-                                entry.progress.max(10.0).min(90.0) // Keep checkpoints within the visible timeline
+                                (entry.percent as f64).clamp(10.0, 90.0) // Keep checkpoints within the visible timeline
                             ),
                             onclick: move |evt: Event<MouseData>| {
                                 evt.stop_propagation();
                             },
-                            title: "{entry.time}: {entry.description}",
+                            title: "{entry.percent}%: {entry.tip.clone().unwrap_or_default()}",
                         }
                     }
                     
@@ -1714,26 +2205,36 @@ fn TimelineSection(guide_data: Signal<GuideData>) -> Element {
                             div {
                                 style: "display: grid; gap: 12px;",
                                 
-                                input {
-                                    r#type: "text",
-                                    value: "{entry.time}",
-                                    oninput: move |evt| {
-                                        let mut data = guide_data();
-                                        if let Some(e) = data.timeline.get_mut(idx) {
-                                            e.time = evt.value();
-                                        }
-                                        guide_data.set(data);
-                                    },
-                                    style: "padding: 6px 8px; border: 1px solid var(--color-border); border-radius: 4px; background: var(--color-bg-primary);",
-                                    placeholder: "Time/phase (e.g., '5 mins', 'Early Game')",
+                                label {
+                                    style: "display: flex; align-items: center; gap: 8px; font-size: 12px; color: var(--color-text-secondary);",
+                                    "Game progress"
+                                    input {
+                                        r#type: "number",
+                                        min: "0",
+                                        max: "100",
+                                        value: "{entry.percent}",
+                                        onchange: move |evt| {
+                                            if let Ok(percent) = evt.value().parse::<u8>() {
+                                                let mut data = guide_data();
+                                                if let Some(e) = data.timeline.get_mut(idx) {
+                                                    e.percent = percent.min(100);
+                                                }
+                                                data.timeline.sort_by_key(|entry| entry.percent);
+                                                guide_data.set(data);
+                                            }
+                                        },
+                                        style: "width: 64px; padding: 6px 8px; border: 1px solid var(--color-border); border-radius: 4px; background: var(--color-bg-primary);",
+                                    }
+                                    "%"
                                 }
                                 
                                 textarea {
-                                    value: "{entry.description}",
+                                    value: "{entry.tip.clone().unwrap_or_default()}",
                                     oninput: move |evt| {
                                         let mut data = guide_data();
                                         if let Some(e) = data.timeline.get_mut(idx) {
-                                            e.description = evt.value();
+                                            let tip = evt.value();
+                                            e.tip = if tip.is_empty() { None } else { Some(tip) };
                                         }
                                         guide_data.set(data);
                                     },
@@ -1762,69 +2263,6 @@ fn TimelineSection(guide_data: Signal<GuideData>) -> Element {
 
 #[component]
 fn SkillOrderSection(guide_data: Signal<GuideData>, god_info: Option<crate::data::gods::God>) -> Element {
-    // Helper function to check if a skill can be leveled at a specific level
-    let can_level_skill = |skill_idx: usize, level: usize, current_order: &[u8]| -> bool {
-        let level_1based = level + 1; // Convert to 1-based level
-        
-        // Count current ranks in each skill
-        let mut skill_ranks = [0; 4];
-        for &skill in current_order.iter() {
-            if skill > 0 && skill <= 4 {
-                skill_ranks[skill as usize - 1] += 1;
-            }
-        }
-        
-        // Total points allocated so far
-        let total_points: u32 = skill_ranks.iter().sum();
-        
-        // Can't allocate more points than levels
-        if total_points >= level_1based as u32 {
-            return false;
-        }
-        
-        let current_rank = skill_ranks[skill_idx];
-        
-        // All skills max at rank 5
-        if current_rank >= 5 {
-            return false;
-        }
-        
-        // Check rank caps based on level
-        let max_rank_for_level = match level_1based {
-            1..=2 => 1,   // Can only have 1 point in abilities at levels 1-2
-            3..=4 => 2,   // Can have 2 points in abilities at levels 3-4
-            5..=8 => 3,   // Can have 3 points in abilities at levels 5-8
-            9..=14 => 4,  // Can have 4 points in abilities at levels 9-14
-            15..=20 => 5, // Can have 5 points in abilities at levels 15+
-            _ => 0,
-        };
-        
-        // Special case for ultimate: additional restrictions on when it can be leveled
-        if skill_idx == 3 { // Ultimate (skill 4)
-            let max_ult_rank_for_level = match level_1based {
-                1..=4 => 0,   // Can't level ultimate until level 5
-                5..=8 => 1,   // Max 1 rank until level 9
-                9..=12 => 2,  // Max 2 ranks until level 13
-                13..=16 => 3, // Max 3 ranks until level 17
-                17..=19 => 4, // Max 4 ranks until level 20
-                20 => 5,      // Max 5 ranks at level 20
-                _ => 0,
-            };
-            
-            // Ultimate has its own stricter cap
-            if current_rank >= max_ult_rank_for_level {
-                return false;
-            }
-        }
-        
-        // Check if this skill would exceed the rank cap for this level
-        if current_rank >= max_rank_for_level {
-            return false;
-        }
-        
-        true
-    };
-
     rsx! {
         div {
             // Section header with clear button
@@ -1897,7 +2335,7 @@ fn SkillOrderSection(guide_data: Signal<GuideData>, god_info: Option<crate::data
                                             } else { 0 };
                                             // This level is selected if the count increased
                                             let is_selected = skill_count_at_level > skill_count_before;
-                                            let can_select = can_level_skill(idx - 1, level, &guide_data().skill_order);
+                                            let can_select = can_level_skill(idx as u8, level as u8 + 1, &guide_data().skill_order);
                                             
                                             format!(
                                                 "aspect-ratio: 1; border: 1px solid var(--color-border); border-radius: 4px; background: {}; color: {}; cursor: {}; font-size: 12px; opacity: {};",
@@ -1933,11 +2371,11 @@ fn SkillOrderSection(guide_data: Signal<GuideData>, god_info: Option<crate::data
                                                     .count()
                                             } else { 0 };
                                             let is_selected = skill_count_at_level > skill_count_before;
-                                            let can_select = can_level_skill(idx - 1, level, &guide_data().skill_order);
+                                            let can_select = can_level_skill(idx as u8, level as u8 + 1, &guide_data().skill_order);
                                             !can_select && !is_selected
                                         },
                                         onclick: move |_| {
-                                            let can_select = can_level_skill(idx - 1, level, &guide_data().skill_order);
+                                            let can_select = can_level_skill(idx as u8, level as u8 + 1, &guide_data().skill_order);
                                             
                                             let mut data = guide_data();
                                             
@@ -2177,4 +2615,66 @@ fn StrategyGuideSection(guide_data: Signal<GuideData>) -> Element {
             }
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::guides::GUIDES;
+
+    #[test]
+    fn test_guide_data_round_trips_losslessly() {
+        for guide in GUIDES.values().flatten() {
+            assert_eq!(&GuideData::from_guide(guide.clone()).to_guide(), guide);
+        }
+
+        let titled = Guide { title: Some("Burn it all".to_string()), ..Guide::new("agni", "mid") };
+        assert_eq!(GuideData::from_guide(titled.clone()).to_guide(), titled);
+    }
+
+    #[test]
+    fn test_drafts_only_hold_unsaved_work() {
+        let blank = GuideData::blank("agni".to_string(), "mid".to_string());
+        assert_eq!(blank.draft(None), None);
+
+        let mut data = blank.clone();
+        data.build.push("rod_of_tahuti".to_string());
+        assert_eq!(data.draft(None).map(|draft| draft.saved_id), Some(None));
+
+        let saved = SavedGuide::new(data.to_saved_guide(), 1);
+        assert_eq!(data.draft(Some(&saved)), None);
+
+        data.strategy = "Burn".to_string();
+        assert_eq!(data.draft(Some(&saved)).and_then(|draft| draft.saved_id), Some(saved.id.clone()));
+    }
+
+    #[test]
+    fn test_only_text_edits_merge() {
+        let before = GuideData::from_guide(GUIDES["agni"][0].clone());
+
+        let mut typed = before.clone();
+        typed.strategy.push('!');
+        assert_eq!(GuideData::merge_key(&before, &typed), Some("strategy"));
+
+        let mut tip = before.clone();
+        tip.timeline[0].tip = Some("Start".to_string());
+        assert_eq!(GuideData::merge_key(&before, &tip), Some("timeline_tip"));
+
+        let mut percent = before.clone();
+        percent.timeline[1].percent += 5;
+        assert_eq!(GuideData::merge_key(&before, &percent), Some("timeline_percent"));
+
+        // A tip and a percent changed together aren't one typed-in field
+        let mut both = tip.clone();
+        both.timeline[1].percent += 5;
+        assert_eq!(GuideData::merge_key(&before, &both), None);
+
+        let mut removed = before.clone();
+        removed.timeline.pop();
+        assert_eq!(GuideData::merge_key(&before, &removed), None);
+
+        let mut build = before.clone();
+        build.build.swap(0, 1);
+        assert_eq!(GuideData::merge_key(&before, &build), None);
+    }
 }
\ No newline at end of file
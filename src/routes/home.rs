@@ -1,8 +1,13 @@
 use dioxus::prelude::*;
 use crate::components::{Header, ClassFilters, RoleFilters, Explain, GodGrid, ClearFilters, Tooltip};
+use crate::routes::links::{use_link_sync, LinkScope, LinkState};
+use crate::Route;
 
+/// Layout for the god browser; the god, role and filters come from the URL
 #[component]
 pub fn Home() -> Element {
+    use_link_sync(LinkState::from_route(&use_route::<Route>()), LinkScope::Home);
+
     let selected_god = use_context::<Signal<crate::SelectedGod>>();
     let has_selection = selected_god().0.is_some();
 
@@ -35,4 +40,20 @@ pub fn Home() -> Element {
         }
         Tooltip {}
     }
-} 
\ No newline at end of file
+} 
+
+// The URL state is read by `Home`, so these routes have nothing of their own to render
+#[component]
+pub fn Browse(class: String, role: String) -> Element {
+    rsx! {}
+}
+
+#[component]
+pub fn GodPage(god: String, class: String, role: String) -> Element {
+    rsx! {}
+}
+
+#[component]
+pub fn GodRolePage(god: String, build_role: String, class: String, role: String) -> Element {
+    rsx! {}
+}
\ No newline at end of file
@@ -0,0 +1,255 @@
+use dioxus::prelude::*;
+use crate::components::{Header, TextWithIcons};
+use crate::data::item_browser::{group_label, item_group, sort_items, stat_columns, SortKey, ITEM_GROUPS};
+use crate::data::item_filter::ItemFilter;
+use crate::data::item_tree::{builds_from, builds_into, total_cost, upgrade_cost};
+use crate::data::items::{Item, ITEMS};
+use crate::data::search::{guides_mentioning, SearchTarget};
+use crate::routes::links::{LinkScope, LinkState};
+use crate::utils::format::{format_stat_label, format_stat_name, format_stat_value};
+use crate::utils::format_god_image_name;
+use crate::{FilteredClass, FilteredRole, SelectedBuild, Route};
+
+/// Every item in a sortable table, with a detail pane for the one in the URL
+#[component]
+pub fn Items(item: String) -> Element {
+    let mut query = use_signal(String::new);
+    let mut sort = use_signal(|| SortKey::Price);
+    let mut descending = use_signal(|| true);
+
+    let filter = ItemFilter::parse(&query());
+    let mut visible: Vec<(&String, &Item)> = ITEMS.iter()
+        .filter(|(id, data)| filter.matches(id, data))
+        .collect();
+    sort_items(&mut visible, &sort(), descending());
+    let columns = stat_columns(visible.iter().map(|(_, data)| *data));
+
+    let groups: Vec<_> = ITEM_GROUPS.iter().cloned().map(Some).chain([None])
+        .map(|group| {
+            let rows: Vec<(&String, &Item)> = visible.iter()
+                .filter(|(_, data)| item_group(data) == group)
+                .cloned()
+                .collect();
+            (group_label(group.as_ref()), rows)
+        })
+        .filter(|(_, rows)| !rows.is_empty())
+        .collect();
+
+    let mut sort_by = move |key: SortKey| {
+        if sort() == key {
+            descending.set(!descending());
+        } else {
+            descending.set(key.descending_by_default());
+            sort.set(key);
+        }
+    };
+    let arrow = move |key: &SortKey| match (sort() == *key, descending()) {
+        (false, _) => "",
+        (true, true) => " ▼",
+        (true, false) => " ▲",
+    };
+
+    let column_count = columns.len() + 2;
+    let selected = item.clone();
+
+    rsx! {
+        div {
+            class: "container",
+            div {
+                style: "padding: 12px 24px; border-bottom: 1px solid var(--color-border);",
+                Header {}
+            }
+            div {
+                class: "items-page",
+                div {
+                    class: "items-list",
+                    input {
+                        class: "items-search",
+                        r#type: "text",
+                        placeholder: "Filter items, e.g. has:magpen effect:passive price:<2600",
+                        value: "{query}",
+                        oninput: move |evt| query.set(evt.value()),
+                    }
+                    if !filter.unknown.is_empty() {
+                        p { class: "items-warning", "Unknown filter: {filter.unknown.join(\", \")}" }
+                    }
+                    p { class: "items-count", "{visible.len()} of {ITEMS.len()} items" }
+
+                    table {
+                        class: "items-table",
+                        thead {
+                            tr {
+                                th {
+                                    onclick: move |_| sort_by(SortKey::Name),
+                                    "Item{arrow(&SortKey::Name)}"
+                                }
+                                th {
+                                    onclick: move |_| sort_by(SortKey::Price),
+                                    "Price{arrow(&SortKey::Price)}"
+                                }
+                                for stat in columns.iter().cloned() {
+                                    th {
+                                        key: "{stat:?}",
+                                        onclick: {
+                                            let stat = stat.clone();
+                                            move |_| sort_by(SortKey::Stat(stat.clone()))
+                                        },
+                                        "{format_stat_label(&stat)}{arrow(&SortKey::Stat(stat.clone()))}"
+                                    }
+                                }
+                            }
+                        }
+                        tbody {
+                            for (label, rows) in groups {
+                                tr {
+                                    key: "{label}",
+                                    class: "items-group",
+                                    td { colspan: "{column_count}", "{label}" }
+                                }
+                                for (id, data) in rows {
+                                    tr {
+                                        key: "{id}",
+                                        class: if *id == selected { "selected" } else { "" },
+                                        onclick: {
+                                            let id = id.clone();
+                                            move |_| { navigator().replace(Route::Items { item: id.clone() }); }
+                                        },
+                                        td {
+                                            class: "items-name",
+                                            img { src: "/assets/items/{id}.png" }
+                                            "{data.display_name}"
+                                        }
+                                        td {
+                                            if total_cost(id) > 0 { "{total_cost(id)}" }
+                                        }
+                                        for stat in columns.iter() {
+                                            td {
+                                                key: "{stat:?}",
+                                                if let Some(value) = data.stats.get(stat) {
+                                                    "{format_stat_value(stat, *value)}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if ITEMS.contains_key(&item) {
+                    ItemDetail { item_id: item }
+                }
+            }
+        }
+    }
+}
+
+/// Stats, effects and curated guides that use the item
+#[component]
+fn ItemDetail(item_id: String) -> Element {
+    let class = use_context::<Signal<FilteredClass>>();
+    let role = use_context::<Signal<FilteredRole>>();
+    let mut selected_build = use_context::<Signal<SelectedBuild>>();
+
+    let Some(item) = ITEMS.get(&item_id) else {
+        return rsx! {};
+    };
+    let mut stats: Vec<_> = item.stats.iter().collect();
+    stats.sort();
+    let guides = guides_mentioning(&item_id);
+
+    rsx! {
+        aside {
+            class: "items-detail",
+            div {
+                class: "items-detail-header",
+                img { src: "/assets/items/{item_id}.png" }
+                div {
+                    h2 { "{item.display_name}" }
+                    if total_cost(&item_id) > 0 {
+                        span { class: "price", "{total_cost(&item_id)} gold" }
+                    }
+                    if !item.builds_from.is_empty() {
+                        span { class: "items-count", " ({upgrade_cost(&item_id)} to upgrade)" }
+                    }
+                }
+            }
+            div {
+                class: "items-tags",
+                for tag in item.tags.iter() {
+                    span { key: "{tag:?}", class: "items-tag", "{tag:?}" }
+                }
+            }
+
+            if !stats.is_empty() {
+                ul {
+                    class: "items-stats",
+                    for (stat, value) in stats {
+                        li {
+                            key: "{stat:?}",
+                            span { class: "stat-value", "{format_stat_value(stat, *value)}" }
+                            " {format_stat_name(stat)}"
+                        }
+                    }
+                }
+            }
+
+            for (effect, text) in item.effects.iter() {
+                div {
+                    key: "{effect:?}",
+                    class: "items-effect",
+                    h5 { "{effect:?}" }
+                    TextWithIcons { text: text.clone() }
+                }
+            }
+
+            for (label, tree) in [("Builds from", builds_from(&item_id)), ("Builds into", builds_into(&item_id))] {
+                if !tree.is_empty() {
+                    h5 { key: "{label}", "{label}" }
+                    for id in tree.iter() {
+                        button {
+                            key: "{label}-{id}",
+                            class: "items-guide",
+                            onclick: {
+                                let id = id.clone();
+                                move |_| { navigator().replace(Route::Items { item: id.clone() }); }
+                            },
+                            img { src: "/assets/items/{id}.png" }
+                            "{ITEMS[id].display_name}"
+                            span { class: "items-count", "{total_cost(id)}" }
+                        }
+                    }
+                }
+            }
+
+            h5 { "Used in guides" }
+            if guides.is_empty() {
+                p { class: "items-count", "No curated guide builds it" }
+            }
+            for guide in guides {
+                if let SearchTarget::Guide { god, role: build_role, id } = guide.target.clone() {
+                    button {
+                        key: "{id}",
+                        class: "items-guide",
+                        onclick: {
+                            let god = god.clone();
+                            move |_| {
+                                selected_build.set(SelectedBuild(Some(id.clone())));
+                                navigator().push(LinkState {
+                                    god: Some(god.clone()),
+                                    build_role: Some(build_role.clone()),
+                                    class: class().0,
+                                    role: role().0,
+                                }.route(LinkScope::Home));
+                            }
+                        },
+                        img { src: "/assets/gods/{format_god_image_name(&god)}.png" }
+                        "{guide.title}"
+                        span { class: "items-count", "{guide.detail}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use dioxus::prelude::*;
+use crate::{FilteredClass, FilteredRole, SelectedGod, SelectedRole, Route};
+
+/// Which parts of the selection a page keeps in its URL
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LinkScope {
+    Home,       // god, role and filters
+    Cheatsheet, // filters only
+}
+
+/// Selection and filter state as it appears in a URL
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LinkState {
+    pub god: Option<String>,
+    pub build_role: Option<String>, // role of the guide being viewed
+    pub class: Option<String>,      // class filter
+    pub role: Option<String>,       // role filter
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    if value.is_empty() { None } else { Some(value.to_string()) }
+}
+
+impl LinkState {
+    pub fn from_route(route: &Route) -> LinkState {
+        match route {
+            Route::Browse { class, role } | Route::Cheatsheet { class, role } => LinkState {
+                class: non_empty(class),
+                role: non_empty(role),
+                ..Default::default()
+            },
+            Route::GodPage { god, class, role } => LinkState {
+                god: non_empty(god),
+                build_role: None,
+                class: non_empty(class),
+                role: non_empty(role),
+            },
+            Route::GodRolePage { god, build_role, class, role } => LinkState {
+                god: non_empty(god),
+                build_role: non_empty(build_role),
+                class: non_empty(class),
+                role: non_empty(role),
+            },
+            _ => LinkState::default(),
+        }
+    }
+
+    /// The same state with only the parts `scope` keeps in its URL
+    fn restrict(self, scope: LinkScope) -> LinkState {
+        match scope {
+            LinkScope::Home => self,
+            LinkScope::Cheatsheet => LinkState { god: None, build_role: None, ..self },
+        }
+    }
+
+    pub fn route(&self, scope: LinkScope) -> Route {
+        let class = self.class.clone().unwrap_or_default();
+        let role = self.role.clone().unwrap_or_default();
+        match (scope, &self.god, &self.build_role) {
+            (LinkScope::Cheatsheet, _, _) => Route::Cheatsheet { class, role },
+            (LinkScope::Home, None, _) => Route::Browse { class, role },
+            (LinkScope::Home, Some(god), None) => Route::GodPage { god: god.clone(), class, role },
+            (LinkScope::Home, Some(god), Some(build_role)) => Route::GodRolePage {
+                god: god.clone(),
+                build_role: build_role.clone(),
+                class,
+                role,
+            },
+        }
+    }
+}
+
+/// Keep the selection context signals and the URL in step.
+///
+/// The URL wins when it changes (links, back/forward); selection changes push a
+/// new history entry. A bare URL on first load keeps the selection restored from
+/// localStorage and is rewritten in place.
+pub fn use_link_sync(url_state: LinkState, scope: LinkScope) {
+    let mut god = use_context::<Signal<SelectedGod>>();
+    let mut build_role = use_context::<Signal<SelectedRole>>();
+    let mut class = use_context::<Signal<FilteredClass>>();
+    let mut role = use_context::<Signal<FilteredRole>>();
+
+    // Last state written to or read from the URL
+    let mut synced = use_signal(LinkState::default);
+    let mut mounted = use_signal(|| false);
+
+    // URL -> signals
+    use_effect(use_reactive!(|url_state| {
+        let url_state = url_state.restrict(scope);
+        if !*mounted.peek() && url_state == LinkState::default() {
+            return;
+        }
+
+        if scope == LinkScope::Home {
+            if god.peek().0 != url_state.god {
+                god.set(SelectedGod(url_state.god.clone()));
+            }
+            if build_role.peek().0 != url_state.build_role {
+                build_role.set(SelectedRole(url_state.build_role.clone()));
+            }
+        }
+        if class.peek().0 != url_state.class {
+            class.set(FilteredClass(url_state.class.clone()));
+        }
+        if role.peek().0 != url_state.role {
+            role.set(FilteredRole(url_state.role.clone()));
+        }
+        synced.set(url_state);
+    }));
+
+    // Signals -> URL
+    use_effect(move || {
+        let state = LinkState {
+            god: god().0,
+            build_role: build_role().0,
+            class: class().0,
+            role: role().0,
+        }.restrict(scope);
+
+        if state != *synced.peek() {
+            let route = state.route(scope);
+            if *mounted.peek() {
+                navigator().push(route);
+            } else {
+                navigator().replace(route);
+            }
+            synced.set(state);
+        }
+        mounted.set(true);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routes_round_trip_through_urls() {
+        let states = [
+            LinkState::default(),
+            LinkState { class: Some("mage".into()), role: Some("mid".into()), ..Default::default() },
+            LinkState { god: Some("agni".into()), ..Default::default() },
+            LinkState { god: Some("agni".into()), build_role: Some("mid".into()), role: Some("jungle".into()), ..Default::default() },
+        ];
+        for state in states {
+            let url = state.route(LinkScope::Home).to_string();
+            let route: Route = url.parse().unwrap();
+            assert_eq!(LinkState::from_route(&route), state, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_route_shapes() {
+        let state = LinkState {
+            god: Some("agni".into()),
+            build_role: Some("mid".into()),
+            class: Some("mage".into()),
+            role: None,
+        };
+        assert!(state.route(LinkScope::Home).to_string().starts_with("/god/agni/mid?"));
+        assert_eq!(LinkState::default().route(LinkScope::Home).to_string().split('?').next(), Some("/"));
+
+        let cheatsheet = state.route(LinkScope::Cheatsheet);
+        assert!(cheatsheet.to_string().starts_with("/cheatsheet?"));
+        assert_eq!(LinkState::from_route(&cheatsheet).god, None);
+    }
+}
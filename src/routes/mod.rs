@@ -1,3 +1,10 @@
 pub mod cheatsheet;
 pub mod home;
-pub mod guide_creator;
\ No newline at end of file
+pub mod guide_creator;
+pub mod simulate;
+pub mod my_guides;
+pub mod shared_guide;
+pub mod links;
+pub mod compare;
+pub mod items;
+pub mod patch_notes;
\ No newline at end of file
@@ -0,0 +1,254 @@
+use dioxus::prelude::*;
+use wasm_bindgen::JsValue;
+use crate::components::{Header, GuideContent, Tooltip, ShareButton};
+use crate::data::gods::GODS;
+use crate::data::saved_guides::{SavedGuide, SavedGuides};
+use crate::utils::format_god_image_name;
+use crate::Route;
+
+const BUTTON_STYLE: &str = "padding: 6px 12px; background: var(--color-bg-tertiary); border: 1px solid var(--color-border); border-radius: 4px; color: var(--color-text-primary); font-size: 12px; cursor: pointer;";
+
+fn display_title(saved: &SavedGuide) -> String {
+    saved.guide.title.clone().unwrap_or_else(|| "Untitled Guide".to_string())
+}
+
+/// Saved timestamps are milliseconds since the epoch
+fn format_created_at(created_at: &str) -> String {
+    match created_at.parse::<f64>() {
+        Ok(ms) => String::from(js_sys::Date::new(&JsValue::from_f64(ms)).to_locale_date_string("default", &JsValue::UNDEFINED)),
+        Err(_) => created_at.to_string(),
+    }
+}
+
+#[component]
+pub fn MyGuides() -> Element {
+    let mut guides = use_context::<Signal<SavedGuides>>();
+    let mut renaming = use_signal(|| None::<String>);
+    let mut rename_text = use_signal(String::new);
+
+    let mut commit_rename = move |id: String| {
+        let saved = guides.read().get(&id).cloned();
+        if let Some(mut saved) = saved {
+            let title = rename_text().trim().to_string();
+            saved.guide.title = if title.is_empty() { None } else { Some(title) };
+            guides.write().save(saved);
+        }
+        renaming.set(None);
+    };
+
+    rsx! {
+        div {
+            style: "display: flex; flex-direction: column; min-height: 100vh; background: var(--color-bg-primary);",
+
+            div {
+                style: "padding: 12px 24px; border-bottom: 1px solid var(--color-border); background: var(--color-bg-secondary);",
+                Header {}
+            }
+
+            div {
+                style: "padding: 24px; max-width: 960px; width: 100%; margin: 0 auto; box-sizing: border-box;",
+
+                div {
+                    style: "display: flex; align-items: center; justify-content: space-between; margin-bottom: 16px;",
+                    h3 {
+                        style: "margin: 0; font-size: 18px; font-weight: 600; color: var(--color-text-primary);",
+                        "My Guides"
+                    }
+                    button {
+                        style: "padding: 8px 16px; background: var(--color-accent); border: none; border-radius: 6px; color: white; font-weight: 600; cursor: pointer;",
+                        onclick: move |_| {
+                            navigator().push(Route::GuideCreator);
+                        },
+                        "New Guide"
+                    }
+                }
+
+                if guides.read().0.is_empty() {
+                    div {
+                        style: "padding: 32px; text-align: center; color: var(--color-text-secondary); background: var(--color-bg-secondary); border-radius: 8px;",
+                        "No saved guides yet. Guides you save in the creator show up here."
+                    }
+                }
+
+                div {
+                    style: "display: flex; flex-direction: column; gap: 8px;",
+                    // Newest first
+                    for saved in guides().0.into_iter().rev() {
+                        div {
+                            key: "{saved.id}",
+                            style: "display: flex; align-items: center; gap: 16px; padding: 12px 16px; background: var(--color-bg-secondary); border: 1px solid var(--color-border); border-radius: 8px;",
+
+                            img {
+                                src: format!("/assets/gods/{}.png", format_god_image_name(&saved.guide.god_id)),
+                                style: "width: 48px; height: 48px; border-radius: 6px; object-fit: cover; background: var(--color-bg-tertiary);",
+                            }
+
+                            div {
+                                style: "flex: 1; min-width: 0; display: flex; flex-direction: column; gap: 4px;",
+
+                                if renaming().as_ref() == Some(&saved.id) {
+                                    input {
+                                        r#type: "text",
+                                        value: "{rename_text}",
+                                        autofocus: true,
+                                        style: "font-size: 15px; font-weight: 600; padding: 4px 8px; border: 1px solid var(--color-accent); border-radius: 4px; background: var(--color-bg-primary); color: var(--color-text-primary);",
+                                        oninput: move |evt| rename_text.set(evt.value()),
+                                        onkeydown: {
+                                            let id = saved.id.clone();
+                                            move |evt: KeyboardEvent| match evt.key() {
+                                                Key::Enter => commit_rename(id.clone()),
+                                                Key::Escape => renaming.set(None),
+                                                _ => {}
+                                            }
+                                        },
+                                        onblur: {
+                                            let id = saved.id.clone();
+                                            move |_| commit_rename(id.clone())
+                                        },
+                                    }
+                                } else {
+                                    Link {
+                                        to: Route::SavedGuideView { id: saved.id.clone() },
+                                        style: "font-size: 15px; font-weight: 600; color: var(--color-text-primary); text-decoration: none;",
+                                        "{display_title(&saved)}"
+                                    }
+                                }
+
+                                span {
+                                    style: "font-size: 12px; color: var(--color-text-secondary);",
+                                    {
+                                        let god = GODS.get(&saved.guide.god_id)
+                                            .map(|god| god.display_name.clone())
+                                            .unwrap_or_else(|| saved.guide.god_id.clone());
+                                        format!("{} · {} · {}", god, saved.guide.role, format_created_at(&saved.created_at))
+                                    }
+                                }
+                            }
+
+                            button {
+                                style: BUTTON_STYLE,
+                                onclick: {
+                                    let id = saved.id.clone();
+                                    move |_| {
+                                        navigator().push(Route::GuideEdit { id: id.clone() });
+                                    }
+                                },
+                                "Edit"
+                            }
+                            button {
+                                style: BUTTON_STYLE,
+                                onclick: {
+                                    let id = saved.id.clone();
+                                    let title = display_title(&saved);
+                                    move |_| {
+                                        rename_text.set(title.clone());
+                                        renaming.set(Some(id.clone()));
+                                    }
+                                },
+                                "Rename"
+                            }
+                            button {
+                                style: BUTTON_STYLE,
+                                onclick: {
+                                    let saved = saved.clone();
+                                    move |_| {
+                                        let mut guide = saved.guide.clone();
+                                        guide.title = Some(format!("{} (copy)", display_title(&saved)));
+                                        guides.write().save(SavedGuide::new(guide, js_sys::Date::now() as u64));
+                                    }
+                                },
+                                "Duplicate"
+                            }
+                            button {
+                                style: "padding: 6px 12px; background: #dc2626; border: none; border-radius: 4px; color: white; font-size: 12px; cursor: pointer;",
+                                onclick: {
+                                    let id = saved.id.clone();
+                                    let title = display_title(&saved);
+                                    move |_| {
+                                        let confirmed = web_sys::window()
+                                            .and_then(|w| w.confirm_with_message(&format!("Delete \"{}\"?", title)).ok())
+                                            .unwrap_or(false);
+                                        if confirmed {
+                                            guides.write().delete(&id);
+                                        }
+                                    }
+                                },
+                                "Delete"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A saved guide rendered with the same layout as curated guides
+#[component]
+pub fn SavedGuideView(id: String) -> Element {
+    let saved_guides = use_context::<Signal<SavedGuides>>();
+    let saved = saved_guides.read().get(&id).cloned();
+    let Some(saved) = saved else {
+        return rsx! {
+            div {
+                style: "padding: 24px;",
+                Header {}
+                p { "Saved guide not found." }
+                Link { to: Route::MyGuides, "Back to My Guides" }
+            }
+        };
+    };
+
+    let god_name = saved.guide.god_id.clone();
+    let god_display = GODS.get(&god_name)
+        .map(|god| god.display_name.clone())
+        .unwrap_or_else(|| god_name.clone());
+
+    rsx! {
+        div {
+            class: "container",
+            div {
+                style: "padding: 12px 24px; border-bottom: 1px solid var(--color-border);",
+                Header {}
+            }
+            div {
+                class: "main-content",
+                div {
+                    class: "explain-top",
+                    img {
+                        class: "god-img",
+                        src: "/assets/gods/{format_god_image_name(&god_name)}.png"
+                    }
+                    div {
+                        class: "explain-title",
+                        h1 { "{display_title(&saved)}" }
+                        span { "{god_display} · {saved.guide.role}" }
+                    }
+                    div {
+                        class: "role-buttons",
+                        button {
+                            class: "role-button",
+                            onclick: {
+                                let id = id.clone();
+                                move |_| {
+                                    navigator().push(Route::GuideEdit { id: id.clone() });
+                                }
+                            },
+                            "Edit"
+                        }
+                        button {
+                            class: "role-button",
+                            onclick: move |_| {
+                                navigator().push(Route::MyGuides);
+                            },
+                            "My Guides"
+                        }
+                        ShareButton { guide: saved.guide.clone() }
+                    }
+                }
+                GuideContent { guide: saved.guide.clone() }
+            }
+        }
+        Tooltip {}
+    }
+}
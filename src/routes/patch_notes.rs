@@ -0,0 +1,256 @@
+use dioxus::prelude::*;
+use crate::components::{GuideImpactList, Header, TextWithIcons};
+use crate::data::gods::GODS;
+use crate::data::guide_sources::{all_guides, GuideEntry};
+use crate::data::saved_guides::SavedGuides;
+use crate::data::patch_diff::{diff_patches, format_detail, side, AbilityChange, ItemChange};
+use crate::data::patches::{current_patch, find_patch, PatchData, PATCHES};
+use crate::data::staleness::{staleness_report, GuideImpact};
+use crate::routes::links::{LinkScope, LinkState};
+use crate::utils::format::format_stat_label;
+use crate::utils::format_god_image_name;
+use crate::{FilteredClass, FilteredRole, SelectedBuild, Route};
+
+/// Item and ability changes between two bundled patches. Defaults to the
+/// patch before the current one.
+#[component]
+pub fn PatchNotes(from: String, to: String) -> Element {
+    let to_patch = find_patch(&to).unwrap_or_else(current_patch);
+    let from_patch = find_patch(&from).unwrap_or_else(|| {
+        let index = PATCHES.iter().position(|patch| patch.id == to_patch.id).unwrap_or_default();
+        &PATCHES[index.saturating_sub(1)]
+    });
+    let diff = diff_patches(from_patch, to_patch);
+
+    // Curated and saved guides that use something the patch touched
+    let saved_guides = use_context::<Signal<SavedGuides>>();
+    let entries: Vec<GuideEntry> = {
+        let mut by_god: Vec<_> = all_guides(&saved_guides.read().0).into_iter().collect();
+        by_god.sort_by(|a, b| a.0.cmp(&b.0));
+        by_god.into_iter().flat_map(|(_, entries)| entries).collect()
+    };
+    let stale = staleness_report(&entries, &diff);
+
+    let pick = move |is_from: bool| {
+        let (from, to) = (from_patch.id, to_patch.id);
+        move |evt: FormEvent| {
+            let (from, to) = if is_from { (evt.value(), to.to_string()) } else { (from.to_string(), evt.value()) };
+            navigator().replace(Route::PatchNotes { from, to });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "container",
+            div {
+                style: "padding: 12px 24px; border-bottom: 1px solid var(--color-border);",
+                Header {}
+            }
+            div {
+                class: "patch-notes",
+                div {
+                    class: "patch-notes-pick",
+                    "Patch "
+                    select {
+                        value: "{from_patch.id}",
+                        onchange: pick(true),
+                        for patch in PATCHES.iter() {
+                            option { key: "{patch.id}", value: "{patch.id}", selected: patch.id == from_patch.id, "{patch.id}" }
+                        }
+                    }
+                    " to "
+                    select {
+                        value: "{to_patch.id}",
+                        onchange: pick(false),
+                        for patch in PATCHES.iter() {
+                            option { key: "{patch.id}", value: "{patch.id}", selected: patch.id == to_patch.id, "{patch.id}" }
+                        }
+                    }
+                }
+
+                if diff.is_empty() {
+                    p { class: "items-count", "Nothing changed between these patches." }
+                }
+
+                if !stale.is_empty() {
+                    h3 { "Guides to review ({stale.len()})" }
+                }
+                for guide in stale {
+                    StaleGuideCard { key: "{guide.entry.id}", entry: guide.entry, impact: guide.impact }
+                }
+
+                if !diff.added_items.is_empty() || !diff.removed_items.is_empty() || !diff.changed_items.is_empty() {
+                    h3 { "Items" }
+                }
+                for id in diff.added_items.iter() {
+                    ItemHeading { key: "added-{id}", id: id.clone(), patch: to_patch.id, mark: "New" }
+                }
+                for id in diff.removed_items.iter() {
+                    ItemHeading { key: "removed-{id}", id: id.clone(), patch: from_patch.id, mark: "Removed" }
+                }
+                for change in diff.changed_items.iter() {
+                    ItemChangeCard { key: "{change.id}", change: change.clone(), patch: to_patch.id }
+                }
+
+                if !diff.added_abilities.is_empty() || !diff.removed_abilities.is_empty() || !diff.changed_abilities.is_empty() {
+                    h3 { "Abilities" }
+                }
+                for (ids, patch, mark) in [(&diff.added_abilities, to_patch, "New"), (&diff.removed_abilities, from_patch, "Removed")] {
+                    for id in ids.iter() {
+                        div {
+                            key: "{mark}-{id}",
+                            class: "patch-change",
+                            h4 {
+                                img { src: "/assets/abilities/{id}.png" }
+                                {ability_name(patch, id)}
+                                span { class: "patch-mark", "{mark}" }
+                            }
+                        }
+                    }
+                }
+                for change in diff.changed_abilities.iter() {
+                    AbilityChangeCard { key: "{change.id}", change: change.clone(), patch: to_patch.id }
+                }
+
+                details {
+                    class: "patch-report",
+                    summary { "Plain text" }
+                    pre { "{diff.report()}" }
+                }
+            }
+        }
+    }
+}
+
+/// A guide the patch affects, opening it next to its god
+#[component]
+fn StaleGuideCard(entry: GuideEntry, impact: GuideImpact) -> Element {
+    let class = use_context::<Signal<FilteredClass>>();
+    let role = use_context::<Signal<FilteredRole>>();
+    let mut selected_build = use_context::<Signal<SelectedBuild>>();
+
+    let god = entry.guide.god_id.clone();
+    let god_name = GODS.get(&god).map(|info| info.display_name.clone()).unwrap_or_else(|| god.clone());
+
+    rsx! {
+        div {
+            class: "patch-change",
+            button {
+                class: "items-guide",
+                onclick: {
+                    let god = god.clone();
+                    move |_| {
+                        selected_build.set(SelectedBuild(Some(entry.id.clone())));
+                        navigator().push(LinkState {
+                            god: Some(god.clone()),
+                            build_role: Some(entry.guide.role.clone()),
+                            class: class().0,
+                            role: role().0,
+                        }.route(LinkScope::Home));
+                    }
+                },
+                img { src: "/assets/gods/{format_god_image_name(&god)}.png" }
+                "{god_name} {entry.guide.role}"
+                span { class: "items-count", "{entry.label()}" }
+            }
+            GuideImpactList { impact }
+        }
+    }
+}
+
+fn item_name(patch: &PatchData, id: &str) -> String {
+    patch.items.get(id).map(|item| item.display_name.clone()).unwrap_or_else(|| id.to_string())
+}
+
+fn ability_name(patch: &PatchData, id: &str) -> String {
+    patch.abilities.get(id).map(|ability| ability.display_name.clone()).unwrap_or_else(|| id.to_string())
+}
+
+/// Icon and name of an item, opening it in the item browser
+#[component]
+fn ItemHeading(id: String, patch: &'static str, #[props(default)] mark: &'static str) -> Element {
+    let name = find_patch(patch).map(|patch| item_name(patch, &id)).unwrap_or_else(|| id.clone());
+    rsx! {
+        h4 {
+            Link {
+                to: Route::Items { item: id.clone() },
+                img { src: "/assets/items/{id}.png" }
+                "{name}"
+            }
+            if !mark.is_empty() {
+                span { class: "patch-mark", "{mark}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn ItemChangeCard(change: ItemChange, patch: &'static str) -> Element {
+    rsx! {
+        div {
+            class: "patch-change",
+            ItemHeading { id: change.id.clone(), patch }
+            ul {
+                if let Some((before, after)) = &change.name {
+                    li { "Renamed from {before} to {after}" }
+                }
+                if let Some((before, after)) = change.price {
+                    li { "Price {before} → {after}" }
+                }
+                for stat in change.stats.iter() {
+                    li {
+                        key: "{stat.key:?}",
+                        "{format_stat_label(&stat.key)} "
+                        span { class: "patch-before", "{side(&stat.before)}" }
+                        " → "
+                        span { class: "patch-after", "{side(&stat.after)}" }
+                    }
+                }
+            }
+            for effect in change.effects.iter() {
+                div {
+                    key: "{effect.key:?}",
+                    class: "patch-effect",
+                    h5 { "{effect.key:?}" }
+                    if let Some(before) = &effect.before {
+                        div { class: "patch-before", TextWithIcons { text: before.clone() } }
+                    }
+                    if let Some(after) = &effect.after {
+                        div { class: "patch-after", TextWithIcons { text: after.clone() } }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AbilityChangeCard(change: AbilityChange, patch: &'static str) -> Element {
+    let name = find_patch(patch).map(|patch| ability_name(patch, &change.id)).unwrap_or_else(|| change.id.clone());
+    rsx! {
+        div {
+            class: "patch-change",
+            h4 {
+                img { src: "/assets/abilities/{change.id}.png" }
+                "{name}"
+            }
+            ul {
+                if let Some((before, after)) = &change.name {
+                    li { "Renamed from {before} to {after}" }
+                }
+                if change.description {
+                    li { "Description reworded" }
+                }
+                for detail in change.details.iter() {
+                    li {
+                        key: "{detail.key}",
+                        "{detail.key.replace('_', \" \")} "
+                        span { class: "patch-before", "{side(&detail.before.as_ref().map(format_detail))}" }
+                        " → "
+                        span { class: "patch-after", "{side(&detail.after.as_ref().map(format_detail))}" }
+                    }
+                }
+            }
+        }
+    }
+}
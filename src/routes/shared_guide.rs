@@ -0,0 +1,80 @@
+use dioxus::prelude::*;
+use crate::components::{Header, GuideContent, Tooltip, ShareButton};
+use crate::data::gods::GODS;
+use crate::data::share::decode_guide;
+use crate::data::saved_guides::{SavedGuide, SavedGuides};
+use crate::utils::format_god_image_name;
+use crate::Route;
+
+/// A guide decoded from a share link, shown read-only
+#[component]
+pub fn SharedGuide(code: String) -> Element {
+    let mut saved_guides = use_context::<Signal<SavedGuides>>();
+    let guide = match decode_guide(&code) {
+        Ok(guide) => guide,
+        Err(e) => {
+            return rsx! {
+                div {
+                    style: "padding: 24px;",
+                    Header {}
+                    p { "This guide link is broken: {e}" }
+                    Link { to: "/", "Back to Grappul" }
+                }
+            };
+        }
+    };
+
+    let god_name = guide.god_id.clone();
+    let god_display = GODS.get(&god_name)
+        .map(|god| god.display_name.clone())
+        .unwrap_or_else(|| god_name.clone());
+    let title = guide.title.clone().unwrap_or_else(|| god_display.clone());
+
+    rsx! {
+        div {
+            class: "container",
+            div {
+                style: "padding: 12px 24px; border-bottom: 1px solid var(--color-border);",
+                Header {}
+            }
+            div {
+                class: "main-content",
+                div {
+                    class: "explain-top",
+                    img {
+                        class: "god-img",
+                        src: "/assets/gods/{format_god_image_name(&god_name)}.png"
+                    }
+                    div {
+                        class: "explain-title",
+                        h1 {
+                            "{title}"
+                            span { class: "guide-badge mine", "Shared" }
+                        }
+                        span { "{god_display} · {guide.role}" }
+                    }
+                    div {
+                        class: "role-buttons",
+                        button {
+                            class: "role-button",
+                            title: "Keep a copy in My Guides",
+                            onclick: {
+                                let guide = guide.clone();
+                                move |_| {
+                                    let saved = SavedGuide::new(guide.clone(), js_sys::Date::now() as u64);
+                                    let id = saved.id.clone();
+                                    saved_guides.write().save(saved);
+                                    navigator().push(Route::SavedGuideView { id });
+                                }
+                            },
+                            "Save to My Guides"
+                        }
+                        ShareButton { guide: guide.clone() }
+                    }
+                }
+                GuideContent { guide: guide.clone() }
+            }
+        }
+        Tooltip {}
+    }
+}
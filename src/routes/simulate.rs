@@ -0,0 +1,417 @@
+use dioxus::prelude::*;
+use crate::components::{Header, Ability, Item, SKILL_ORDER};
+use crate::data::ability_calc::Attacker;
+use crate::data::build_stats::BuildStats;
+use crate::data::aliases::same_role;
+use crate::data::gods::{GODS, MAX_LEVEL};
+use crate::data::guide_sources::{guides_for_god, GuideEntry, GuideSource};
+use crate::data::guides::Guide;
+use crate::data::items::ITEMS;
+use crate::data::saved_guides::{SavedGuide, SavedGuides};
+use crate::data::simulation::{simulate, Defender, Hit};
+use crate::utils::unslug;
+use crate::{SelectedGod, SelectedRole};
+
+const SECTION_STYLE: &str = "background: var(--color-bg-secondary); border: 1px solid var(--color-border); border-radius: 8px; padding: 16px 20px;";
+const SELECT_STYLE: &str = "padding: 8px 12px; background: var(--color-bg-primary); border: 1px solid var(--color-border); border-radius: 4px; color: var(--color-text-primary);";
+
+// The god's guides the attacker can start from, the user's own before curated ones
+fn attacker_guides(god: &str, saved: &[SavedGuide]) -> Vec<GuideEntry> {
+    let mut entries = guides_for_god(god, saved);
+    entries.sort_by_key(|entry| entry.source != GuideSource::Mine);
+    entries
+}
+
+// The first guide for the role (aliases like "adc" count), otherwise the first guide
+fn pick_guide<'a>(entries: &'a [GuideEntry], role: &str) -> Option<&'a GuideEntry> {
+    entries.iter()
+        .find(|entry| same_role(&entry.guide.role, role))
+        .or(entries.first())
+}
+
+// Items and skill order of a guide, where the attacker starts
+fn guide_loadout(god: &str, guide: Option<&Guide>) -> (Vec<String>, Vec<u8>) {
+    let default_order = || SKILL_ORDER.get(god).cloned().unwrap_or_default();
+
+    match guide {
+        Some(guide) => (
+            guide.build.iter().chain(guide.relics.iter()).cloned().collect(),
+            if guide.skill_order.is_empty() { default_order() } else { guide.skill_order.clone() },
+        ),
+        None => (Vec::new(), default_order()),
+    }
+}
+
+// "3 2 2 1 4" style skill orders; anything but 1-4 is ignored
+fn parse_skill_order(text: &str) -> Vec<u8> {
+    text.chars()
+        .filter_map(|c| c.to_digit(10))
+        .filter(|skill| (1..=4).contains(skill))
+        .map(|skill| skill as u8)
+        .take(MAX_LEVEL as usize)
+        .collect()
+}
+
+#[component]
+pub fn Simulate() -> Element {
+    let selected_god = use_context::<Signal<SelectedGod>>();
+    let selected_role = use_context::<Signal<SelectedRole>>();
+    let saved_guides = use_context::<Signal<SavedGuides>>();
+
+    let mut god_names: Vec<String> = GODS.keys().cloned().collect();
+    god_names.sort();
+    // Targets can only be picked from gods with base stats; the rest is typed in
+    let target_names: Vec<String> = god_names.iter()
+        .filter(|name| GODS[*name].stats.is_some())
+        .cloned()
+        .collect();
+
+    let mut attacker_god = use_signal(|| {
+        selected_god().0
+            .filter(|god| GODS.contains_key(god))
+            .unwrap_or_else(|| god_names[0].clone())
+    });
+    let mut guide_id = use_signal(|| {
+        let role = selected_role.peek().0.clone().unwrap_or_default();
+        pick_guide(&attacker_guides(&attacker_god.peek(), &saved_guides.peek().0), &role).map(|entry| entry.id.clone())
+    });
+    let mut level = use_signal(|| MAX_LEVEL);
+    let mut defender_god = use_signal(|| {
+        let attacker = attacker_god.peek().clone();
+        if GODS[&attacker].stats.is_some() { attacker } else { String::new() }
+    });
+    let mut defender = use_signal(|| {
+        GODS.get(&*defender_god.peek())
+            .and_then(|god| god.stats_at_level(MAX_LEVEL))
+            .map(|stats| Defender::from_stats(&stats))
+            .unwrap_or_default()
+    });
+
+    let guides = attacker_guides(&attacker_god(), &saved_guides.read().0);
+    let guide = guides.iter().find(|entry| Some(&entry.id) == guide_id().as_ref());
+
+    let initial = use_hook(|| guide_loadout(&attacker_god.peek(), guide.map(|entry| &entry.guide)));
+    let mut items = use_signal(|| initial.0.clone());
+    let mut skill_order = use_signal(|| initial.1.clone());
+    // Switch to one of the god's guides, or the god's best match for the role when `id` is None
+    let mut load_guide = move |god: String, id: Option<String>| {
+        let entries = attacker_guides(&god, &saved_guides.peek().0);
+        let entry = match id {
+            Some(id) => entries.iter().find(|entry| entry.id == id),
+            None => pick_guide(&entries, &selected_role.peek().0.clone().unwrap_or_default()),
+        };
+        let (guide_items, guide_order) = guide_loadout(&god, entry.map(|entry| &entry.guide));
+        items.set(guide_items);
+        skill_order.set(guide_order);
+        guide_id.set(entry.map(|entry| entry.id.clone()));
+        attacker_god.set(god);
+    };
+
+    let god = &GODS[&attacker_god()];
+
+    let mut item_choices: Vec<(&String, &String)> = ITEMS.iter()
+        .map(|(id, item)| (id, &item.display_name))
+        .collect();
+    item_choices.sort_by(|a, b| a.1.cmp(b.1));
+    let skill_order_text = skill_order().iter().map(|skill| skill.to_string()).collect::<Vec<_>>().join(" ");
+
+    let attacker = Attacker::new(god, level(), BuildStats::from_items(items().iter()));
+    let simulation = simulate(god, &skill_order(), &attacker, &defender());
+
+    rsx! {
+        div {
+            style: "display: flex; flex-direction: column; min-height: 100vh; background: var(--color-bg-primary);",
+
+            div {
+                style: "padding: 12px 24px; border-bottom: 1px solid var(--color-border); background: var(--color-bg-secondary);",
+                Header {}
+            }
+
+            // Attacker controls
+            div {
+                style: "padding: 16px 24px; display: flex; align-items: center; gap: 16px; flex-wrap: wrap; background: var(--color-bg-tertiary); border-bottom: 1px solid var(--color-border);",
+
+                span { style: "font-weight: 600; color: var(--color-text-primary);", "Attacker" }
+
+                select {
+                    style: SELECT_STYLE,
+                    value: "{attacker_god}",
+                    onchange: move |evt| load_guide(evt.value(), None),
+                    for name in god_names.iter() {
+                        option { key: "{name}", value: "{name}", "{GODS[name].display_name}" }
+                    }
+                }
+
+                if guides.len() > 1 {
+                    select {
+                        style: SELECT_STYLE,
+                        value: "{guide_id().unwrap_or_default()}",
+                        onchange: move |evt| load_guide(attacker_god(), Some(evt.value())),
+                        for entry in guides.iter() {
+                            option { key: "{entry.id}", value: "{entry.id}", "{entry.guide.role} · {entry.label()}" }
+                        }
+                    }
+                }
+
+                div {
+                    style: "display: flex; align-items: center; gap: 8px; color: var(--color-text-secondary);",
+                    span { "Level {level}" }
+                    input {
+                        r#type: "range",
+                        min: "1",
+                        max: "{MAX_LEVEL}",
+                        value: "{level}",
+                        oninput: move |evt| {
+                            if let Ok(value) = evt.value().parse::<u8>() {
+                                level.set(value);
+                            }
+                        },
+                    }
+                }
+
+                if god.stats.is_none() {
+                    span {
+                        style: "font-size: 12px; color: var(--color-text-secondary);",
+                        "No base stats for {god.display_name} yet - basic attacks are left out"
+                    }
+                }
+
+                if guide.is_none() {
+                    span {
+                        style: "font-size: 12px; color: var(--color-text-secondary);",
+                        "No guide for this god - add items below"
+                    }
+                }
+            }
+
+            // Attacker build, seeded from the guide
+            div {
+                style: "padding: 12px 24px; display: flex; align-items: center; gap: 12px; flex-wrap: wrap; border-bottom: 1px solid var(--color-border);",
+
+                span { style: "font-weight: 600; color: var(--color-text-primary);", "Items" }
+                for (i, item) in items().into_iter().enumerate() {
+                    button {
+                        key: "{i}_{item}",
+                        style: "padding: 0; background: none; border: none; cursor: pointer;",
+                        title: "Remove",
+                        onclick: move |_| {
+                            items.write().remove(i);
+                        },
+                        Item { item: item.clone(), size: 32 }
+                    }
+                }
+                select {
+                    style: SELECT_STYLE,
+                    value: "",
+                    onchange: move |evt| {
+                        if !evt.value().is_empty() {
+                            items.write().push(evt.value());
+                        }
+                    },
+                    option { value: "", "Add item..." }
+                    for (id, name) in item_choices {
+                        option { key: "{id}", value: "{id}", "{name}" }
+                    }
+                }
+
+                label {
+                    style: "display: flex; align-items: center; gap: 8px; color: var(--color-text-secondary);",
+                    "Skill order"
+                    input {
+                        style: "{SELECT_STYLE} width: 220px; font-family: monospace;",
+                        value: "{skill_order_text}",
+                        placeholder: "3 2 2 1 4 ...",
+                        onchange: move |evt| skill_order.set(parse_skill_order(&evt.value())),
+                    }
+                }
+
+                if guide.is_some() {
+                    button {
+                        style: SELECT_STYLE,
+                        onclick: move |_| load_guide(attacker_god(), guide_id()),
+                        "Reset to guide"
+                    }
+                }
+            }
+
+            div {
+                style: "padding: 24px; display: grid; grid-template-columns: minmax(260px, 1fr) 2fr; gap: 24px; align-items: start;",
+
+                // Defender profile
+                div {
+                    style: SECTION_STYLE,
+                    h5 { "Target" }
+
+                    div {
+                        style: "display: flex; flex-direction: column; gap: 10px;",
+
+                        if target_names.is_empty() {
+                            span {
+                                class: "stats-missing",
+                                "No god has stat data yet, so targets can't be picked by god. Enter the target's numbers below."
+                            }
+                        } else {
+                            select {
+                                style: SELECT_STYLE,
+                                value: "{defender_god}",
+                                onchange: move |evt| {
+                                    let name = evt.value();
+                                    if let Some(stats) = GODS.get(&name).and_then(|target| target.stats_at_level(level())) {
+                                        defender.set(Defender::from_stats(&stats));
+                                    }
+                                    defender_god.set(name);
+                                },
+                                option { value: "", "Custom target" }
+                                for name in target_names.iter() {
+                                    option { key: "{name}", value: "{name}", "{GODS[name].display_name}" }
+                                }
+                            }
+                        }
+
+                        DefenderField {
+                            label: "Health",
+                            value: defender().health,
+                            onchange: move |value| defender.write().health = value,
+                        }
+                        DefenderField {
+                            label: "Physical Protection",
+                            value: defender().physical_protection,
+                            onchange: move |value| defender.write().physical_protection = value,
+                        }
+                        DefenderField {
+                            label: "Magical Protection",
+                            value: defender().magical_protection,
+                            onchange: move |value| defender.write().magical_protection = value,
+                        }
+                        DefenderField {
+                            label: "Flat Mitigation",
+                            value: defender().flat_mitigation,
+                            onchange: move |value| defender.write().flat_mitigation = value,
+                        }
+                        DefenderField {
+                            label: "Percent Mitigation",
+                            value: defender().percent_mitigation,
+                            onchange: move |value: f64| defender.write().percent_mitigation = value.clamp(0.0, 100.0),
+                        }
+                    }
+                }
+
+                // Results
+                div {
+                    style: "display: flex; flex-direction: column; gap: 24px;",
+
+                    div {
+                        style: SECTION_STYLE,
+                        h5 { "Time to kill" }
+                        div {
+                            style: "font-size: 28px; font-weight: 700; color: var(--color-accent);",
+                            match simulation.time_to_kill {
+                                _ if defender().health <= 0.0 => rsx! { "Set the target's health" },
+                                Some(ttk) => rsx! { "{ttk:.2}s" },
+                                None => rsx! { "No kill" },
+                            }
+                        }
+                        div {
+                            style: "font-size: 12px; color: var(--color-text-secondary); margin-top: 4px;",
+                            "Fastest order of ability casts, with basic attacks or waiting while everything is on cooldown. Passives, crits and item effects are not included."
+                        }
+                    }
+
+                    div {
+                        style: SECTION_STYLE,
+                        h5 { "Damage per hit" }
+                        div {
+                            style: "display: flex; flex-direction: column; gap: 6px;",
+                            for hit in simulation.abilities.iter() {
+                                HitRow { key: "{hit.name}", hit: hit.clone() }
+                            }
+                            if let Some(basic_attack) = simulation.basic_attack.clone() {
+                                HitRow { hit: basic_attack }
+                            }
+                        }
+                    }
+
+                    div {
+                        style: SECTION_STYLE,
+                        h5 { "Combo" }
+                        div {
+                            style: "display: flex; flex-direction: column; gap: 4px; font-size: 13px;",
+                            for (i, step) in simulation.combo.iter().enumerate() {
+                                div {
+                                    key: "{i}",
+                                    style: "display: grid; grid-template-columns: 60px 1fr 70px 80px; gap: 8px; color: var(--color-text-secondary);",
+                                    span { "{step.time:.2}s" }
+                                    span { style: "color: var(--color-text-primary);", "{unslug(&step.name)}" }
+                                    span { style: "text-align: right;", "-{step.damage:.0}" }
+                                    span { style: "text-align: right;", "{step.remaining_health:.0} HP" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DefenderField(label: &'static str, value: f64, onchange: EventHandler<f64>) -> Element {
+    rsx! {
+        label {
+            style: "display: flex; justify-content: space-between; align-items: center; gap: 12px; font-size: 13px; color: var(--color-text-secondary);",
+            "{label}"
+            input {
+                r#type: "number",
+                min: "0",
+                style: "width: 90px; padding: 4px 8px; background: var(--color-bg-primary); border: 1px solid var(--color-border); border-radius: 4px; color: var(--color-text-primary);",
+                value: "{value:.0}",
+                oninput: move |evt| {
+                    if let Ok(value) = evt.value().parse::<f64>() {
+                        onchange.call(value.max(0.0));
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn HitRow(hit: Hit) -> Element {
+    let unleveled = hit.slot.is_some() && hit.rank == 0;
+
+    rsx! {
+        div {
+            style: format!(
+                "display: flex; align-items: center; gap: 12px; opacity: {};",
+                if unleveled { "0.5" } else { "1" }
+            ),
+            if hit.slot.is_some() {
+                Ability { ab: hit.name.clone(), size: 32 }
+            } else {
+                div { style: "width: 32px;" }
+            }
+            span {
+                style: "flex: 1; color: var(--color-text-primary);",
+                "{unslug(&hit.name)}"
+                if hit.rank > 0 {
+                    span { style: "color: var(--color-text-secondary);", " (rank {hit.rank})" }
+                }
+            }
+            span {
+                style: "width: 90px; text-align: right; color: var(--color-text-secondary);",
+                "{hit.raw:.0} raw"
+            }
+            span {
+                style: "width: 90px; text-align: right; font-weight: 600; color: var(--color-text-primary);",
+                "{hit.effective:.0}"
+            }
+            span {
+                style: "width: 70px; text-align: right; color: var(--color-text-secondary);",
+                match hit.cooldown {
+                    Some(cooldown) => rsx! { "{cooldown:.1}s" },
+                    None => rsx! { "" },
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use wasm_bindgen_futures::JsFuture;
+use web_sys::window;
+
+/// Absolute URL for an in-app path like "/g/abc"
+pub fn absolute_url(path: &str) -> String {
+    let origin = window()
+        .and_then(|w| w.location().origin().ok())
+        .unwrap_or_default();
+    format!("{}{}", origin, path)
+}
+
+/// Put text on the clipboard, waiting for the browser to accept it
+pub async fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    let window = window().ok_or("no browser window")?;
+    let promise = window.navigator().clipboard().write_text(text);
+    JsFuture::from(promise).await.map(|_| ()).map_err(|e| {
+        e.as_string()
+            .or_else(|| js_sys::Reflect::get(&e, &"message".into()).ok().and_then(|message| message.as_string()))
+            .unwrap_or_else(|| "the clipboard isn't available".to_string())
+    })
+}
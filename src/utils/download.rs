@@ -0,0 +1,40 @@
+use wasm_bindgen::JsCast;
+use web_sys::{window, Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+use crate::utils::set_timeout;
+
+// How long the object URL outlives the click
+const REVOKE_DELAY_MS: i32 = 10_000;
+
+/// Offer `contents` to the user as a downloaded file
+pub fn download_file(filename: &str, mime_type: &str, contents: &str) -> bool {
+    let Some(document) = window().and_then(|w| w.document()) else {
+        return false;
+    };
+
+    let parts = js_sys::Array::of1(&contents.into());
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let Ok(blob) = Blob::new_with_str_sequence_and_options(&parts, &options) else {
+        return false;
+    };
+    let Ok(url) = Url::create_object_url_with_blob(&blob) else {
+        return false;
+    };
+
+    let downloaded = document
+        .create_element("a")
+        .ok()
+        .and_then(|element| element.dyn_into::<HtmlAnchorElement>().ok())
+        .map(|anchor| {
+            anchor.set_href(&url);
+            anchor.set_download(filename);
+            anchor.click();
+        })
+        .is_some();
+
+    // Revoking straight after the click can cancel the download in Firefox and Safari
+    set_timeout(REVOKE_DELAY_MS, move || {
+        let _ = Url::revoke_object_url(&url);
+    });
+    downloaded
+}
@@ -30,4 +30,27 @@ pub fn format_stat_name(stat: &ItemStat) -> &'static str {
         ItemStat::BasicAttackDamage => "Basic Attack Damage",
         ItemStat::DamageReduction => "Damage Reduction",
     }
+}
+
+/// Stat name that tells flat and percent penetration apart, for column headers and pickers
+pub fn format_stat_label(stat: &ItemStat) -> String {
+    match stat {
+        ItemStat::PhysicalPenetrationPercent | ItemStat::MagicalPenetrationPercent => format!("{} %", format_stat_name(stat)),
+        _ => format_stat_name(stat).to_string(),
+    }
+}
+
+/// Format a stat amount with the unit the game displays it in
+pub fn format_stat_value(stat: &ItemStat, value: i32) -> String {
+    match stat {
+        ItemStat::AttackSpeed
+        | ItemStat::PhysicalLifesteal
+        | ItemStat::MagicalLifesteal
+        | ItemStat::PhysicalPenetrationPercent
+        | ItemStat::MagicalPenetrationPercent
+        | ItemStat::CriticalStrikeChance
+        | ItemStat::CooldownReduction
+        | ItemStat::MovementSpeed => format!("{}%", value),
+        _ => value.to_string(),
+    }
 }
\ No newline at end of file
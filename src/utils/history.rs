@@ -0,0 +1,135 @@
+use std::collections::VecDeque;
+
+/// Edits to the same field closer together than this become one undo step
+pub const MERGE_WINDOW_MS: f64 = 1000.0;
+
+/// Bounded undo/redo stacks of snapshots around a current value
+#[derive(Debug, Clone, PartialEq)]
+pub struct History<T> {
+    current: T,
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    limit: usize,
+    // Merge key and time of the last recorded edit
+    last_edit: Option<(&'static str, f64)>,
+}
+
+impl<T: Clone + PartialEq> History<T> {
+    pub fn new(initial: T, limit: usize) -> Self {
+        History {
+            current: initial,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            limit,
+            last_edit: None,
+        }
+    }
+
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    /// Record that the value changed to `next`.
+    /// Consecutive edits with the same `merge_key` inside `MERGE_WINDOW_MS`
+    /// (typing into one text field, say) undo together.
+    pub fn record(&mut self, next: T, merge_key: Option<&'static str>, now_ms: f64) {
+        if next == self.current {
+            return;
+        }
+
+        let merges = match (merge_key, self.last_edit) {
+            (Some(key), Some((last_key, at))) => key == last_key && now_ms - at < MERGE_WINDOW_MS,
+            _ => false,
+        };
+        if !merges {
+            self.undo.push_back(std::mem::replace(&mut self.current, next));
+            if self.undo.len() > self.limit {
+                self.undo.pop_front();
+            }
+        } else {
+            self.current = next;
+        }
+
+        self.redo.clear();
+        self.last_edit = merge_key.map(|key| (key, now_ms));
+    }
+
+    /// Step back, returning the restored value
+    pub fn undo(&mut self) -> Option<T> {
+        let previous = self.undo.pop_back()?;
+        self.redo.push(std::mem::replace(&mut self.current, previous));
+        self.last_edit = None;
+        Some(self.current.clone())
+    }
+
+    /// Step forward again after an undo
+    pub fn redo(&mut self) -> Option<T> {
+        let next = self.redo.pop()?;
+        self.undo.push_back(std::mem::replace(&mut self.current, next));
+        self.last_edit = None;
+        Some(self.current.clone())
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_redo() {
+        let mut history = History::new(0, 10);
+        history.record(1, None, 0.0);
+        history.record(2, None, 0.0);
+
+        assert_eq!(history.undo(), Some(1));
+        assert_eq!(history.undo(), Some(0));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(1));
+
+        // A new edit drops the redo stack
+        history.record(5, None, 0.0);
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(), Some(1));
+    }
+
+    #[test]
+    fn test_stack_is_bounded() {
+        let mut history = History::new(0, 3);
+        for i in 1..=5 {
+            history.record(i, None, 0.0);
+        }
+        let mut restored = Vec::new();
+        while let Some(value) = history.undo() {
+            restored.push(value);
+        }
+        assert_eq!(restored, vec![4, 3, 2]);
+    }
+
+    #[test]
+    fn test_typing_merges_into_one_step() {
+        let mut history = History::new(String::new(), 10);
+        history.record("a".to_string(), Some("title"), 0.0);
+        history.record("ab".to_string(), Some("title"), 300.0);
+        history.record("abc".to_string(), Some("title"), 600.0);
+        // Pausing starts a new step
+        history.record("abcd".to_string(), Some("title"), 2000.0);
+
+        assert_eq!(history.undo().as_deref(), Some("abc"));
+        assert_eq!(history.undo().as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_unchanged_values_are_ignored() {
+        let mut history = History::new(1, 10);
+        history.record(1, None, 0.0);
+        assert!(!history.can_undo());
+    }
+}
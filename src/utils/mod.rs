@@ -1,5 +1,12 @@
 pub mod format;
 pub mod storage;
+pub mod download;
+pub mod clipboard;
+pub mod history;
+pub mod timer;
 
 pub use format::*;
-pub use storage::*;
\ No newline at end of file
+pub use storage::*;
+pub use download::*;
+pub use clipboard::*;
+pub use timer::*;
\ No newline at end of file
@@ -1,5 +1,3 @@
-use web_sys::window;
-
 pub const ATTR_FLIP: [&str; 2] = ["cooldown", "mana cost"];
 
 /// Convert a slug like "god_name" to "God Name"
@@ -44,24 +42,85 @@ pub fn simplify(text: &str) -> String {
 
 /// Save a value to browser's local storage
 pub fn save_to_storage(key: &str, value: &str) {
-    if let Some(storage) = window().and_then(|w| w.local_storage().ok()).flatten() {
-        let _ = storage.set_item(key, value);
-    }
+    backend::set(key, value);
 }
 
 /// Load a value from browser's local storage
 pub fn load_from_storage(key: &str) -> Option<String> {
-    window()
-        .and_then(|w| w.local_storage().ok())
-        .flatten()
-        .and_then(|storage| storage.get_item(key).ok())
-        .flatten()
+    backend::get(key)
 }
 
 /// Clear a value from browser's local storage
 pub fn clear_from_storage(key: &str) {
-    if let Some(storage) = window().and_then(|w| w.local_storage().ok()).flatten() {
-        let _ = storage.remove_item(key);
+    backend::remove(key);
+}
+
+/// List the keys in browser's local storage that start with a prefix
+pub fn storage_keys(prefix: &str) -> Vec<String> {
+    backend::keys().into_iter()
+        .filter(|key| key.starts_with(prefix))
+        .collect()
+}
+
+#[cfg(not(test))]
+mod backend {
+    use web_sys::{window, Storage};
+
+    fn storage() -> Option<Storage> {
+        window().and_then(|w| w.local_storage().ok()).flatten()
+    }
+
+    pub fn set(key: &str, value: &str) {
+        if let Some(storage) = storage() {
+            let _ = storage.set_item(key, value);
+        }
+    }
+
+    pub fn get(key: &str) -> Option<String> {
+        storage().and_then(|storage| storage.get_item(key).ok()).flatten()
+    }
+
+    pub fn remove(key: &str) {
+        if let Some(storage) = storage() {
+            let _ = storage.remove_item(key);
+        }
+    }
+
+    pub fn keys() -> Vec<String> {
+        let Some(storage) = storage() else {
+            return Vec::new();
+        };
+        let length = storage.length().unwrap_or(0);
+        (0..length)
+            .filter_map(|i| storage.key(i).ok().flatten())
+            .collect()
+    }
+}
+
+// Tests have no browser, so each test thread gets its own map instead
+#[cfg(test)]
+mod backend {
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+
+    thread_local! {
+        static STORAGE: RefCell<BTreeMap<String, String>> = const { RefCell::new(BTreeMap::new()) };
+    }
+
+    pub fn set(key: &str, value: &str) {
+        STORAGE.with(|storage| storage.borrow_mut().insert(key.to_string(), value.to_string()));
+    }
+
+    pub fn get(key: &str) -> Option<String> {
+        STORAGE.with(|storage| storage.borrow().get(key).cloned())
+    }
+
+    pub fn remove(key: &str) {
+        STORAGE.with(|storage| storage.borrow_mut().remove(key));
+    }
+
+    pub fn keys() -> Vec<String> {
+        STORAGE.with(|storage| storage.borrow().keys().cloned().collect())
     }
 }
 
@@ -0,0 +1,16 @@
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::window;
+
+/// Run `callback` once after `ms` milliseconds. Returns the handle for `clear_timeout`.
+pub fn set_timeout(ms: i32, callback: impl FnOnce() + 'static) -> Option<i32> {
+    let callback = Closure::once_into_js(callback);
+    window()?
+        .set_timeout_with_callback_and_timeout_and_arguments_0(callback.unchecked_ref(), ms)
+        .ok()
+}
+
+pub fn clear_timeout(handle: i32) {
+    if let Some(window) = window() {
+        window.clear_timeout_with_handle(handle);
+    }
+}